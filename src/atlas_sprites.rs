@@ -13,24 +13,33 @@
 //!   configuration when text changes.
 //! - Optional gizmo rendering for debugging purposes, available with the
 //!   "gizmos" feature flag.
+//! - `ImageFontTypewriter` component: Reveals the text one glyph at a time.
+//! - Per-glyph animation effects, such as waving or shaking text, selected
+//!   through `ImageFontSpriteText::effects` and `ImageFontSpan::effects`.
 //!
 //! This module is intended for advanced text rendering use cases, offering
 //! fine-grained control over how text is displayed in the game world.
 
 #[cfg(feature = "gizmos")]
 pub mod gizmos;
+mod glyph_effects;
+mod typewriter;
 
 use std::fmt::Debug;
 
 use bevy::prelude::*;
+use bevy::render::view::VisibilitySystems;
 use bevy::sprite::Anchor;
+use bevy::transform::TransformSystem;
 use derive_setters::Setters;
+pub use glyph_effects::*;
 use tracing::{debug, error};
+pub use typewriter::*;
 
 use crate::render_context::{RenderConfig, RenderContext};
 use crate::{
-    sync_texts_with_font_changes, FontScalingMode, ImageFont, ImageFontSet, ImageFontText,
-    LetterSpacing,
+    sync_texts_with_font_changes, FontScalingMode, ImageFont, ImageFontGlyphEffect, ImageFontSet,
+    ImageFontText, LetterSpacing,
 };
 
 /// Internal plugin for conveniently organizing the code related to this
@@ -40,12 +49,24 @@ pub(crate) struct AtlasSpritesPlugin;
 
 impl Plugin for AtlasSpritesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            PostUpdate,
-            set_up_sprites
-                .after(sync_texts_with_font_changes)
-                .in_set(ImageFontSet),
-        );
+        app.register_type::<ImageFontTypewriter>()
+            .add_event::<ImageFontTypewriterFinished>()
+            .add_systems(
+                PostUpdate,
+                (
+                    set_up_sprites
+                        .after(sync_texts_with_font_changes)
+                        .in_set(ImageFontSet),
+                    reveal_typewriter_text
+                        .after(set_up_sprites)
+                        .before(VisibilitySystems::VisibilityPropagate)
+                        .in_set(ImageFontSet),
+                    animate_glyph_effects
+                        .after(set_up_sprites)
+                        .before(TransformSystem::TransformPropagate)
+                        .in_set(ImageFontSet),
+                ),
+            );
 
         #[cfg(feature = "gizmos")]
         {
@@ -63,6 +84,7 @@ impl Plugin for AtlasSpritesPlugin {
 ///   position.
 /// - `color`: Uniform tint applied to all glyphs.
 /// - `scaling_mode`: Controls how scaling is applied to glyph dimensions.
+/// - `effects`: Animations applied to the individual glyphs.
 #[derive(Debug, Clone, Reflect, Default, Component, Setters)]
 #[setters(into)]
 #[require(ImageFontText, Visibility)]
@@ -73,7 +95,9 @@ pub struct ImageFontSpriteText {
     pub anchor: Anchor,
 
     /// The color applied to the rendered text. This color affects all glyphs
-    /// equally, allowing you to tint the text uniformly.
+    /// equally, allowing you to tint the text uniformly. Spans of the text
+    /// with a [`color`](crate::ImageFontSpan::color) of their own are tinted
+    /// with that color instead.
     pub color: Color,
 
     /// Determines how scaling is applied to the glyph dimensions when adjusting
@@ -91,6 +115,12 @@ pub struct ImageFontSpriteText {
     /// at the font's native height and is scaled proportionally based on the
     /// current font height.
     pub letter_spacing: LetterSpacing,
+
+    /// The effects that animate the glyphs of the text, such as making them
+    /// wave or shake. Spans of the text with
+    /// [`effects`](crate::ImageFontSpan::effects) of their own are animated
+    /// with those instead.
+    pub effects: Vec<ImageFontGlyphEffect>,
 }
 
 /// Stores a mapping between characters and their corresponding sprite entities.
@@ -103,6 +133,9 @@ struct ImageFontTextData {
     /// Basically a map between character index and character sprite
     sprites: Vec<Entity>,
 
+    /// The glyph shown by each of the `sprites`.
+    glyphs: Vec<SpriteGlyph>,
+
     /// Tracks whether a missing font asset has already been reported for this
     /// entity.
     ///
@@ -126,6 +159,22 @@ struct ImageFontTextData {
     #[cfg(feature = "gizmos")]
     gizmo_data: gizmos::ImageFontTextGizmoData,
 }
+
+/// The glyph shown by one of the sprites of an [`ImageFontSpriteText`], as
+/// laid out by [`set_up_sprites`].
+#[derive(Debug, Clone, Copy)]
+struct SpriteGlyph {
+    /// The character the glyph is rendered for.
+    character: char,
+    /// The index of the span the character belongs to, where `0` is the
+    /// text's own `text` and `i` is `spans[i - 1]`.
+    span_index: usize,
+    /// The transform of the sprite before any effects are applied.
+    transform: Transform,
+    /// The color of the sprite before any effects are applied.
+    color: Color,
+}
+
 impl ImageFontTextData {
     /// Creates a new `ImageFontTextData` instance for a given entity.
     ///
@@ -143,6 +192,7 @@ impl ImageFontTextData {
         Self {
             self_entity: entity,
             sprites: default(),
+            glyphs: default(),
             has_reported_missing_font: default(),
             #[cfg(feature = "gizmos")]
             gizmo_data: default(),
@@ -219,6 +269,7 @@ pub fn set_up_sprites(
 
         let Some(render_context) = RenderContext::new(
             image_font,
+            &image_fonts,
             image_font_text,
             render_config,
             &texture_atlas_layouts,
@@ -236,15 +287,23 @@ pub fn set_up_sprites(
             image_font_text_data,
         };
 
-        let x_pos = update_existing_sprites(&mut child_query, &mut sprite_context, &render_context);
+        update_existing_sprites(&mut child_query, &mut sprite_context, &render_context);
 
-        adjust_sprite_count(
-            x_pos,
-            &mut commands,
-            &mut sprite_context,
-            &render_context,
-            image_font_sprite_text,
-        );
+        adjust_sprite_count(&mut commands, &mut sprite_context, &render_context);
+
+        sprite_context.image_font_text_data.glyphs = render_context
+            .layout()
+            .glyphs()
+            .map(|glyph| {
+                let character = glyph.span_character();
+                SpriteGlyph {
+                    character: character.into(),
+                    span_index: character.span_index,
+                    transform: render_context.transform(glyph),
+                    color: render_context.glyph_color(character.span_index),
+                }
+            })
+            .collect();
 
         maybe_insert_new_image_font_text_data(
             &mut commands,
@@ -295,27 +354,23 @@ fn maybe_insert_new_image_font_text_data(
 /// - `sprite_context`: Context for managing the entity and its sprite data.
 /// - `render_context`: Context providing rendering-related information and
 ///   operations.
-///
-/// # Returns
-/// The x-position to the right of the last processed sprite.
 fn update_existing_sprites(
     child_query: &mut Query<(&mut Sprite, &mut Transform)>,
     sprite_context: &mut SpriteContext,
     render_context: &RenderContext,
-) -> f32 {
+) {
     let SpriteContext {
         ref mut image_font_text_data,
         ..
     } = *sprite_context;
 
-    let mut x_pos = 0.;
-
-    for (sprite_entity, character) in image_font_text_data
+    for (sprite_entity, glyph) in image_font_text_data
         .sprites
         .iter()
         .copied()
-        .zip(render_context.text().filtered_chars())
+        .zip(render_context.layout().glyphs())
     {
+        let character = glyph.span_character();
         let (mut sprite, mut transform) = match child_query.get_mut(sprite_entity) {
             Ok(result) => result,
             Err(error) => {
@@ -324,18 +379,9 @@ fn update_existing_sprites(
             }
         };
 
-        let sprite = &mut *sprite;
-        let Some(sprite_texture) = sprite.texture_atlas.as_mut() else {
-            error!(
-                "An ImageFontSpriteText's child sprite was \
-            unexpectedly missing a `texture_atlas`. This will likely cause rendering bugs."
-            );
-            continue;
-        };
+        render_context.update_render_values(character, &mut sprite);
 
-        render_context.update_render_values(character, sprite_texture, &mut sprite.color);
-
-        *transform = render_context.transform(&mut x_pos, character);
+        *transform = render_context.transform(glyph);
 
         #[cfg(feature = "gizmos")]
         gizmos::record_character_dimensions(
@@ -344,54 +390,50 @@ fn update_existing_sprites(
             &mut image_font_text_data.gizmo_data,
         );
     }
-
-    x_pos
 }
 
-/// Ensures the number of sprites matches the number of characters in the text.
+/// Ensures the number of sprites matches the number of glyphs in the text
+/// layout.
 ///
 /// Adds missing sprites or removes excess sprites to maintain consistency
-/// between the text content and the entity's children.
+/// between the text content and the entity's children. The glyphs are counted
+/// rather than the characters of the text, as whitespace at the points where
+/// lines are wrapped isn't laid out.
 ///
 /// # Parameters
-/// - `x_pos`: x-position of where the next sprite should go.
 /// - `commands`: A command buffer for spawning or despawning sprites to
 ///   synchronize with the text content.
 /// - `sprite_context`: Context for managing the entity and its sprite data.
 /// - `render_context`: Context providing rendering-related information and
 ///   operations.
-/// - `sprite_text`: Component defining text appearance (e.g., color).
 #[inline]
 fn adjust_sprite_count(
-    x_pos: f32,
     commands: &mut Commands,
     sprite_context: &mut SpriteContext,
     render_context: &RenderContext,
-    sprite_text: &ImageFontSpriteText,
 ) {
     use std::cmp::Ordering;
 
-    let char_count = render_context.text().filtered_chars().count();
+    let glyph_count = render_context.layout().glyphs().count();
     let sprite_count = sprite_context.image_font_text_data.sprites.len();
 
-    match sprite_count.cmp(&char_count) {
+    match sprite_count.cmp(&glyph_count) {
         Ordering::Greater => {
-            remove_excess_sprites(commands, sprite_context, char_count);
+            remove_excess_sprites(commands, sprite_context, glyph_count);
         }
         Ordering::Less => {
-            add_missing_sprites(x_pos, commands, sprite_context, render_context, sprite_text);
+            add_missing_sprites(commands, sprite_context, render_context);
         }
         Ordering::Equal => {}
     }
 }
 
-/// Removes excess sprites from the text entity to match the new character
-/// count.
+/// Removes excess sprites from the text entity to match the new glyph count.
 ///
 /// # Parameters
 /// - `commands`: Command buffer for despawning entities.
 /// - `sprite_context`: Context for managing the entity and its sprite data.
-/// - `char_count`: The number of characters in the filtered text.
+/// - `glyph_count`: The number of glyphs in the text layout.
 ///
 /// # Side Effects
 /// Excess sprites are despawned from the ECS.
@@ -399,36 +441,32 @@ fn adjust_sprite_count(
 fn remove_excess_sprites(
     commands: &mut Commands,
     sprite_context: &mut SpriteContext,
-    char_count: usize,
+    glyph_count: usize,
 ) {
     for entity in sprite_context
         .image_font_text_data
         .sprites
-        .drain(char_count..)
+        .drain(glyph_count..)
     {
         commands.entity(entity).despawn();
     }
 }
 
-/// Adds missing sprites to the text entity to match the new character count.
+/// Adds missing sprites to the text entity to match the new glyph count.
 ///
-/// If the number of sprites is less than the number of characters in the text,
-/// this function spawns new sprites for the remaining characters and updates
-/// the sprite data accordingly.
+/// If the number of sprites is less than the number of glyphs in the text
+/// layout, this function spawns new sprites for the remaining characters and
+/// updates the sprite data accordingly.
 ///
 /// # Parameters
-/// - `x_pos`: x-position of where the next sprite should go.
 /// - `commands`: Command buffer for spawning new sprite entities.
 /// - `sprite_context`: Context for managing the entity and its sprite data.
 /// - `render_context`: Context providing rendering-related information and
 ///   operations.
-/// - `sprite_text`: Component defining text appearance (e.g., color).
 fn add_missing_sprites(
-    mut x_pos: f32,
     commands: &mut Commands,
     sprite_context: &mut SpriteContext,
     render_context: &RenderContext,
-    sprite_text: &ImageFontSpriteText,
 ) {
     let SpriteContext {
         entity,
@@ -438,16 +476,13 @@ fn add_missing_sprites(
     let current_sprite_count = image_font_text_data.sprites.len();
 
     commands.entity(entity).with_children(|parent| {
-        for character in render_context
-            .text()
-            .filtered_chars()
-            .skip(current_sprite_count)
-        {
-            let transform = render_context.transform(&mut x_pos, character);
+        for glyph in render_context.layout().glyphs().skip(current_sprite_count) {
+            let character = glyph.span_character();
+            let transform = render_context.transform(glyph);
             let sprite = Sprite {
                 image: render_context.font_image(character),
                 texture_atlas: Some(render_context.font_texture_atlas(character)),
-                color: sprite_text.color,
+                color: render_context.glyph_color(character.span_index),
                 ..Default::default()
             };
 
@@ -474,3 +509,53 @@ struct SpriteContext<'data> {
     /// The mutable text sprite data component for the entity.
     image_font_text_data: &'data mut ImageFontTextData,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use super::*;
+    use crate::tests::utils::{
+        initialize_app_with_loaded_example_font, ExampleFont, MONOSPACE_FONT_WIDTH,
+    };
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "the magnitude of the numbers we're working on here are too small to lose \
+            anything"
+    )]
+    fn shortening_wrapped_text_removes_sprites_of_dropped_glyphs() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        let entity = app
+            .world_mut()
+            .spawn((
+                ImageFontText::default()
+                    .text("AAAA AAAA AAAA")
+                    .font(handle)
+                    .max_width(4. * MONOSPACE_FONT_WIDTH as f32),
+                ImageFontSpriteText::default(),
+            ))
+            .id();
+        app.update();
+
+        let sprite_count = |app: &App| {
+            let image_font_text_data = app.world().get::<ImageFontTextData>(entity).unwrap();
+            (
+                image_font_text_data.sprites.len(),
+                app.world().entity(entity).get::<Children>().unwrap().len(),
+            )
+        };
+        // The spaces the lines are wrapped at aren't laid out
+        assert_eq!(sprite_count(&app), (12, 12));
+
+        app.world_mut()
+            .get_mut::<ImageFontText>(entity)
+            .unwrap()
+            .text = String::from("AAAA AAAA");
+        app.update();
+
+        assert_eq!(sprite_count(&app), (8, 8));
+    }
+}
@@ -19,7 +19,7 @@ use bevy::color::palettes::css;
 use bevy::prelude::*;
 
 use crate::atlas_sprites::ImageFontTextData;
-use crate::render_context::RenderContext;
+use crate::render_context::{RenderContext, SpanCharacter};
 use crate::ImageFontText;
 
 /// Initializes the debug gizmo system for `ImageFontSpriteText` components.
@@ -167,7 +167,7 @@ pub struct ImageFontTextGizmoData {
 
 pub(crate) fn record_character_dimensions(
     render_context: &RenderContext,
-    character: char,
+    character: SpanCharacter,
     gizmo_data: &mut ImageFontTextGizmoData,
 ) {
     let (new_width, new_height) = render_context.character_dimensions(character);
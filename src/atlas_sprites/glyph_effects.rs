@@ -0,0 +1,378 @@
+//! This module animates the glyphs of [`ImageFontSpriteText`] with the
+//! [`ImageFontGlyphEffect`]s selected for them.
+//!
+//! [`set_up_sprites`](super::set_up_sprites) records the transform and color
+//! of every glyph's sprite as laid out. Every frame, the
+//! [`animate_glyph_effects`] system starts from those and applies the effects
+//! on top of them, so the effects neither accumulate over time nor get lost
+//! when the text is laid out again.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use super::{ImageFontSpriteText, ImageFontTextData, SpriteGlyph};
+use crate::{ImageFontGlyphEffect, ImageFontText};
+
+/// System that applies the [`ImageFontGlyphEffect`]s of every
+/// [`ImageFontSpriteText`] and its spans to the sprites of their glyphs.
+///
+/// Texts without any effects, and glyphs of spans without any, are left alone.
+#[expect(
+    private_interfaces,
+    reason = "Systems are only `pub` for the sake of allowing dependent crates to use them for ordering"
+)]
+pub fn animate_glyph_effects(
+    query: Query<(
+        Entity,
+        &ImageFontText,
+        &ImageFontSpriteText,
+        &ImageFontTextData,
+    )>,
+    mut sprites: Query<(&mut Sprite, &mut Transform)>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs_f64();
+
+    for (entity, image_font_text, image_font_sprite_text, image_font_text_data) in &query {
+        let span_effects = |span_index: usize| {
+            span_index
+                .checked_sub(1)
+                .and_then(|index| image_font_text.spans.get(index))
+                .and_then(|span| span.effects.as_deref())
+                .unwrap_or(&image_font_sprite_text.effects)
+        };
+        if image_font_sprite_text.effects.is_empty()
+            && image_font_text
+                .spans
+                .iter()
+                .all(|span| span.effects.as_ref().is_none_or(Vec::is_empty))
+        {
+            continue;
+        }
+
+        for (glyph_index, (&sprite_entity, glyph)) in image_font_text_data
+            .sprites
+            .iter()
+            .zip(&image_font_text_data.glyphs)
+            .enumerate()
+        {
+            let effects = span_effects(glyph.span_index);
+            if effects.is_empty() {
+                continue;
+            }
+            let Ok((mut sprite, mut transform)) = sprites.get_mut(sprite_entity) else {
+                continue;
+            };
+
+            let mut animated = AnimatedGlyph {
+                transform: glyph.transform,
+                color: glyph.color,
+            };
+            for effect in effects {
+                animated.apply(effect, glyph, glyph_index, entity.to_bits(), elapsed);
+            }
+
+            // Effects such as a shake leave glyphs in place for several frames
+            transform.set_if_neq(animated.transform);
+            if sprite.color != animated.color {
+                sprite.color = animated.color;
+            }
+        }
+    }
+}
+
+/// The transform and color of a glyph's sprite while effects are applied to
+/// it.
+struct AnimatedGlyph {
+    /// The transform of the sprite.
+    transform: Transform,
+    /// The color of the sprite.
+    color: Color,
+}
+
+impl AnimatedGlyph {
+    /// Applies an effect to the glyph.
+    ///
+    /// # Parameters
+    /// - `effect`: The effect to apply.
+    /// - `glyph`: The glyph as it was laid out, before any effects.
+    /// - `glyph_index`: The index of the glyph in its text.
+    /// - `seed`: A value that differs between texts, so random effects don't
+    ///   move the glyphs of different texts in the same way.
+    /// - `elapsed`: The time since the app started, in seconds.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "glyph indices won't ever be particularly large"
+    )]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "the number of times a shake moved its glyphs fits in a `u64`"
+    )]
+    fn apply(
+        &mut self,
+        effect: &ImageFontGlyphEffect,
+        glyph: &SpriteGlyph,
+        glyph_index: usize,
+        seed: u64,
+        elapsed: f64,
+    ) {
+        // Distances are given at the font's native height
+        let scale = glyph.transform.scale.y;
+
+        match *effect {
+            ImageFontGlyphEffect::Wave {
+                amplitude,
+                frequency,
+                wavelength,
+            } => {
+                // The offsets of the glyphs wouldn't be finite otherwise
+                if wavelength.is_nan() || wavelength <= 0. {
+                    return;
+                }
+                let phase = cycles(elapsed, frequency) - glyph_index as f32 / wavelength;
+                self.transform.translation.y += amplitude * scale * (phase * TAU).sin();
+            }
+            ImageFontGlyphEffect::Shake {
+                magnitude,
+                frequency,
+            } => {
+                let step = (elapsed * f64::from(frequency)).floor() as u64;
+                let seed = seed ^ (glyph_index as u64).rotate_left(32) ^ step.rotate_left(48);
+                self.transform.translation.x += magnitude * scale * noise(seed);
+                self.transform.translation.y += magnitude * scale * noise(!seed);
+            }
+            ImageFontGlyphEffect::Rainbow { frequency, spread } => {
+                let hue = (cycles(elapsed, frequency) * 360. + glyph_index as f32 * spread)
+                    .rem_euclid(360.);
+                self.color = Hsla::new(hue, 1., 0.5, self.color.alpha()).into();
+            }
+            ImageFontGlyphEffect::Pulse { amount, frequency } => {
+                let factor = 1. + amount * (cycles(elapsed, frequency) * TAU).sin();
+                self.transform.scale.x *= factor;
+                self.transform.scale.y *= factor;
+            }
+        }
+    }
+}
+
+/// Computes how far into its current cycle a periodic effect is.
+///
+/// # Parameters
+/// - `elapsed`: The time since the app started, in seconds.
+/// - `frequency`: How many cycles the effect goes through per second.
+///
+/// # Returns
+/// The fraction of the current cycle that has passed, between `0.0` and
+/// `1.0`.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "the fraction of a cycle is between 0 and 1"
+)]
+fn cycles(elapsed: f64, frequency: f32) -> f32 {
+    (elapsed * f64::from(frequency)).fract() as f32
+}
+
+/// Turns a seed into a pseudo-random number.
+///
+/// # Parameters
+/// - `seed`: The seed. The same seed always results in the same number.
+///
+/// # Returns
+/// A number between `-1.0` and `1.0`.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "only 24 bits are converted, which an `f32` represents exactly"
+)]
+fn noise(seed: u64) -> f32 {
+    // The finalizer of SplitMix64
+    let mut bits = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    bits = (bits ^ (bits >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    bits = (bits ^ (bits >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    bits ^= bits >> 31;
+
+    (bits >> 40) as f32 / (1 << 23) as f32 - 1.
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+    use float_eq::assert_float_eq;
+
+    use super::*;
+    use crate::tests::utils::{
+        initialize_app_with_loaded_example_font, ExampleFont, COMPARISON_TOLERANCE,
+    };
+    use crate::ImageFontSpan;
+
+    #[test]
+    fn noise_is_within_range() {
+        for seed in 0..1000 {
+            let value = noise(seed);
+            assert!((-1. ..1.).contains(&value), "{value}");
+        }
+        assert!((noise(1) - noise(2)).abs() > COMPARISON_TOLERANCE);
+    }
+
+    #[test]
+    fn effects_are_applied_on_top_of_the_layout() {
+        let glyph = SpriteGlyph {
+            character: 'A',
+            span_index: 0,
+            transform: Transform::from_xyz(10., 20., 0.).with_scale(Vec3::new(2., 2., 0.)),
+            color: Color::WHITE.with_alpha(0.5),
+        };
+        let mut animated = AnimatedGlyph {
+            transform: glyph.transform,
+            color: glyph.color,
+        };
+
+        // A quarter of the way into the cycle, the wave and pulse are at their
+        // peak
+        for effect in [
+            ImageFontGlyphEffect::Wave {
+                amplitude: 3.,
+                frequency: 1.,
+                wavelength: 4.,
+            },
+            ImageFontGlyphEffect::Pulse {
+                amount: 0.5,
+                frequency: 1.,
+            },
+            ImageFontGlyphEffect::Rainbow {
+                frequency: 1.,
+                spread: 0.,
+            },
+        ] {
+            animated.apply(&effect, &glyph, 0, 0, 0.25);
+        }
+
+        assert_float_eq!(
+            animated.transform.translation.y,
+            26.,
+            abs <= COMPARISON_TOLERANCE
+        );
+        assert_float_eq!(animated.transform.scale.x, 3., abs <= COMPARISON_TOLERANCE);
+        let color = Hsla::from(animated.color);
+        assert_float_eq!(color.hue, 90., abs <= COMPARISON_TOLERANCE);
+        assert_float_eq!(color.alpha, 0.5, abs <= COMPARISON_TOLERANCE);
+
+        // Waves without a positive wavelength leave the glyphs where they are
+        for wavelength in [0., -4., f32::NAN] {
+            let mut animated = AnimatedGlyph {
+                transform: glyph.transform,
+                color: glyph.color,
+            };
+            let wave = ImageFontGlyphEffect::Wave {
+                amplitude: 3.,
+                frequency: 1.,
+                wavelength,
+            };
+            animated.apply(&wave, &glyph, 1, 0, 0.25);
+            assert_eq!(animated.transform, glyph.transform, "{wavelength}");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    fn span_effects_replace_text_effects() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let wave = ImageFontGlyphEffect::Wave {
+            amplitude: 2.,
+            frequency: 1.,
+            wavelength: 4.,
+        };
+        let entity = app
+            .world_mut()
+            .spawn((
+                ImageFontText::default()
+                    .text("A")
+                    .font(handle)
+                    .spans(vec![ImageFontSpan::new("B").effects(Vec::new())]),
+                ImageFontSpriteText::default().effects(vec![wave]),
+            ))
+            .id();
+
+        app.update();
+
+        let translations = |app: &App| {
+            let sprites = &app
+                .world()
+                .get::<ImageFontTextData>(entity)
+                .unwrap()
+                .sprites;
+            sprites
+                .iter()
+                .map(|&sprite| app.world().get::<Transform>(sprite).unwrap().translation)
+                .collect::<Vec<_>>()
+        };
+        let laid_out: Vec<_> = app
+            .world()
+            .get::<ImageFontTextData>(entity)
+            .unwrap()
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.transform.translation)
+            .collect();
+
+        // Some time already passed while the font was loading
+        let wave_offset = |app: &App| {
+            let elapsed = app.world().resource::<Time>().elapsed_secs_f64();
+            2. * (cycles(elapsed, 1.) * TAU).sin()
+        };
+
+        // The glyph of the text moves along the wave, while the span turns the
+        // effects off for its glyph
+        let animated = translations(&app);
+        assert_float_eq!(
+            animated[0].y - laid_out[0].y,
+            wave_offset(&app),
+            abs <= COMPARISON_TOLERANCE
+        );
+        assert!(wave_offset(&app).abs() > 1.);
+        assert_eq!(animated[1], laid_out[1]);
+
+        let last_changed = |app: &App| {
+            let sprites = &app
+                .world()
+                .get::<ImageFontTextData>(entity)
+                .unwrap()
+                .sprites;
+            sprites
+                .iter()
+                .map(|&sprite| {
+                    let sprite = app.world().entity(sprite);
+                    (
+                        sprite.get_ref::<Transform>().unwrap().last_changed(),
+                        sprite.get_ref::<Sprite>().unwrap().last_changed(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let changed_before = last_changed(&app);
+
+        // The effect is computed from the layout every frame, rather than being
+        // added to the previous frame's result
+        app.update();
+        let animated = translations(&app);
+        assert_float_eq!(
+            animated[0].y - laid_out[0].y,
+            wave_offset(&app),
+            abs <= COMPARISON_TOLERANCE
+        );
+
+        // Only the sprite that moved is changed, and only its transform
+        let changed_after = last_changed(&app);
+        assert_ne!(changed_after[0].0, changed_before[0].0);
+        assert_eq!(changed_after[0].1, changed_before[0].1);
+        assert_eq!(changed_after[1], changed_before[1]);
+    }
+}
@@ -0,0 +1,383 @@
+//! This module provides a typewriter effect for [`ImageFontSpriteText`],
+//! revealing its glyphs one at a time.
+//!
+//! Adding an [`ImageFontTypewriter`] component to an entity with an
+//! `ImageFontSpriteText` makes the [`reveal_typewriter_text`] system hide the
+//! sprites of the glyphs that haven't been revealed yet. As only the
+//! visibility of the sprites is changed, the text isn't laid out again while
+//! it is being revealed, and glyphs don't move around as more of them appear.
+//!
+//! Whenever the entity's [`ImageFontText`] changes, e.g. to show the next
+//! line of dialogue, the text is revealed from the start again. Once every
+//! glyph is shown, an [`ImageFontTypewriterFinished`] event is sent.
+
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use derive_setters::Setters;
+
+use super::{ImageFontSpriteText, ImageFontTextData, SpriteGlyph};
+use crate::ImageFontText;
+
+/// Reveals the glyphs of an [`ImageFontSpriteText`] one at a time, like a
+/// typewriter.
+///
+/// See the [module documentation](self) for details. Removing this component
+/// shows the glyphs that are still hidden.
+#[derive(Debug, Clone, Reflect, Component, Setters)]
+#[setters(into)]
+#[require(ImageFontSpriteText)]
+#[component(on_remove = show_hidden_glyphs)]
+#[non_exhaustive]
+pub struct ImageFontTypewriter {
+    /// How many glyphs are revealed per second. If this isn't positive, no
+    /// more glyphs are revealed, which can be used to pause the typewriter.
+    /// The time that passes while it is paused doesn't count towards revealing
+    /// glyphs once it is resumed.
+    ///
+    /// The default value is `30.0`.
+    pub glyphs_per_second: f32,
+
+    /// How long to wait, in seconds, before revealing the glyph after one of
+    /// the `pause_characters`, in addition to the usual delay between glyphs.
+    ///
+    /// The default value is `0.25`.
+    pub punctuation_pause: f32,
+
+    /// The characters after which the typewriter waits an additional
+    /// `punctuation_pause`.
+    ///
+    /// The default value is `".,!?;:"`.
+    pub pause_characters: String,
+
+    /// The number of glyphs that are currently revealed.
+    #[setters(skip)]
+    revealed_glyphs: usize,
+
+    /// The time, in seconds, that has passed since the last glyph was
+    /// revealed.
+    #[setters(skip)]
+    elapsed: f32,
+
+    /// Whether every glyph is revealed and the
+    /// [`ImageFontTypewriterFinished`] event has been sent.
+    #[setters(skip)]
+    finished: bool,
+}
+
+impl Default for ImageFontTypewriter {
+    fn default() -> Self {
+        Self {
+            glyphs_per_second: 30.,
+            punctuation_pause: 0.25,
+            pause_characters: String::from(".,!?;:"),
+            revealed_glyphs: 0,
+            elapsed: 0.,
+            finished: false,
+        }
+    }
+}
+
+impl ImageFontTypewriter {
+    /// Creates a new `ImageFontTypewriter` with the default pauses.
+    ///
+    /// # Parameters
+    /// - `glyphs_per_second`: How many glyphs are revealed per second.
+    #[must_use]
+    pub fn new(glyphs_per_second: f32) -> Self {
+        Self {
+            glyphs_per_second,
+            ..default()
+        }
+    }
+
+    /// Returns the number of glyphs that are currently revealed.
+    #[must_use]
+    #[inline]
+    pub const fn revealed_glyphs(&self) -> usize {
+        self.revealed_glyphs
+    }
+
+    /// Returns whether every glyph of the text is revealed.
+    #[must_use]
+    #[inline]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Reveals the rest of the text at once, e.g. when the player presses a
+    /// button to skip the effect.
+    ///
+    /// The [`ImageFontTypewriterFinished`] event is sent as usual.
+    pub fn skip_to_end(&mut self) {
+        self.revealed_glyphs = usize::MAX;
+    }
+
+    /// Hides the text again and starts revealing it from the start.
+    pub fn restart(&mut self) {
+        self.revealed_glyphs = 0;
+        self.elapsed = 0.;
+        self.finished = false;
+    }
+
+    /// Reveals as many glyphs as the time that has passed allows.
+    ///
+    /// # Parameters
+    /// - `delta`: The time that has passed since the last call, in seconds.
+    /// - `glyphs`: The glyphs of the text, in order.
+    fn advance(&mut self, delta: f32, glyphs: &[SpriteGlyph]) {
+        self.revealed_glyphs = self.revealed_glyphs.min(glyphs.len());
+        if self.glyphs_per_second.is_nan() || self.glyphs_per_second <= 0. {
+            return;
+        }
+        self.elapsed += delta;
+
+        while self.revealed_glyphs < glyphs.len() {
+            let mut delay = self.glyphs_per_second.recip();
+            let follows_pause = self
+                .revealed_glyphs
+                .checked_sub(1)
+                .is_some_and(|previous| self.pause_characters.contains(glyphs[previous].character));
+            if follows_pause {
+                delay += self.punctuation_pause;
+            }
+            if self.elapsed < delay {
+                break;
+            }
+
+            self.elapsed -= delay;
+            self.revealed_glyphs += 1;
+        }
+
+        if self.revealed_glyphs == glyphs.len() {
+            self.elapsed = 0.;
+        }
+    }
+}
+
+/// Shows the sprites of the glyphs that an [`ImageFontTypewriter`] hid when it
+/// is removed from an entity, so the whole text is visible without it.
+///
+/// # Parameters
+/// - `world`: The world the typewriter is removed from.
+/// - `context`: The entity the typewriter is removed from.
+fn show_hidden_glyphs(mut world: DeferredWorld, context: HookContext) {
+    let Some(image_font_text_data) = world.get::<ImageFontTextData>(context.entity) else {
+        return;
+    };
+
+    let sprites = image_font_text_data.sprites.clone();
+    for sprite in sprites {
+        if let Some(mut visibility) = world.get_mut::<Visibility>(sprite) {
+            visibility.set_if_neq(Visibility::Inherited);
+        }
+    }
+}
+
+/// Sent when an [`ImageFontTypewriter`] has revealed every glyph of its text,
+/// including when it is skipped to the end.
+#[derive(Debug, Clone, Event)]
+#[non_exhaustive]
+pub struct ImageFontTypewriterFinished {
+    /// The entity with the [`ImageFontTypewriter`] component.
+    pub entity: Entity,
+}
+
+/// System that reveals the glyphs of every [`ImageFontSpriteText`] with an
+/// [`ImageFontTypewriter`] over time, by hiding the sprites of the glyphs that
+/// haven't been revealed yet.
+#[expect(
+    private_interfaces,
+    reason = "Systems are only `pub` for the sake of allowing dependent crates to use them for ordering"
+)]
+pub fn reveal_typewriter_text(
+    mut query: Query<(
+        Entity,
+        &mut ImageFontTypewriter,
+        Ref<ImageFontText>,
+        &ImageFontTextData,
+    )>,
+    mut visibilities: Query<&mut Visibility>,
+    time: Res<Time>,
+    mut events: EventWriter<ImageFontTypewriterFinished>,
+) {
+    for (entity, mut typewriter, image_font_text, image_font_text_data) in &mut query {
+        if image_font_text.is_changed() {
+            typewriter.restart();
+        } else if typewriter.finished {
+            continue;
+        }
+
+        typewriter.advance(time.delta_secs(), &image_font_text_data.glyphs);
+
+        debug_assert_eq!(
+            image_font_text_data.sprites.len(),
+            image_font_text_data.glyphs.len(),
+            "every sprite should show one glyph"
+        );
+
+        for (index, &sprite) in image_font_text_data.sprites.iter().enumerate() {
+            if let Ok(mut visibility) = visibilities.get_mut(sprite) {
+                visibility.set_if_neq(if index < typewriter.revealed_glyphs {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                });
+            }
+        }
+
+        if typewriter.revealed_glyphs == image_font_text_data.glyphs.len() {
+            typewriter.finished = true;
+            events.write(ImageFontTypewriterFinished { entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::tests::utils::{initialize_app_with_loaded_example_font, ExampleFont};
+
+    #[test]
+    fn advance_pauses_after_punctuation() {
+        let glyphs: Vec<_> = "Hi. Yo"
+            .chars()
+            .map(|character| SpriteGlyph {
+                character,
+                span_index: 0,
+                transform: Transform::IDENTITY,
+                color: Color::WHITE,
+            })
+            .collect();
+        let mut typewriter = ImageFontTypewriter::new(10.).punctuation_pause(0.5_f32);
+
+        typewriter.advance(0.25, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 2);
+
+        // The glyph after the period needs an additional 0.5 seconds
+        typewriter.advance(0.1, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 3);
+        typewriter.advance(0.5, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 3);
+        typewriter.advance(0.1, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 4);
+
+        typewriter.skip_to_end();
+        typewriter.advance(0., &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), glyphs.len());
+    }
+
+    #[test]
+    fn advance_does_not_reveal_glyphs_while_paused() {
+        let glyphs: Vec<_> = "ABCD"
+            .chars()
+            .map(|character| SpriteGlyph {
+                character,
+                span_index: 0,
+                transform: Transform::IDENTITY,
+                color: Color::WHITE,
+            })
+            .collect();
+        let mut typewriter = ImageFontTypewriter::new(10.);
+
+        typewriter.advance(0.15, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 1);
+
+        // The time that passes while paused is not made up for when resuming
+        for glyphs_per_second in [0., -10., f32::NAN] {
+            typewriter.glyphs_per_second = glyphs_per_second;
+            typewriter.advance(1., &glyphs);
+            assert_eq!(typewriter.revealed_glyphs(), 1, "{glyphs_per_second}");
+        }
+
+        typewriter.glyphs_per_second = 10.;
+        typewriter.advance(0.05, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 2);
+        typewriter.advance(0.05, &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), 2);
+
+        // Skipping to the end works while paused
+        typewriter.glyphs_per_second = 0.;
+        typewriter.skip_to_end();
+        typewriter.advance(0., &glyphs);
+        assert_eq!(typewriter.revealed_glyphs(), glyphs.len());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    fn reveals_sprites_over_time() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let entity = app
+            .world_mut()
+            .spawn((
+                ImageFontText::default().text("ABCD").font(handle),
+                ImageFontTypewriter::new(10.),
+            ))
+            .id();
+
+        let visible_sprites = |app: &App| {
+            let sprites = &app
+                .world()
+                .get::<ImageFontTextData>(entity)
+                .unwrap()
+                .sprites;
+            sprites
+                .iter()
+                .filter(|&&sprite| {
+                    app.world().get::<Visibility>(sprite) != Some(&Visibility::Hidden)
+                })
+                .count()
+        };
+
+        // Every update advances the time by 0.1 seconds, revealing one glyph
+        app.update();
+        assert_eq!(visible_sprites(&app), 1);
+        app.update();
+        assert_eq!(visible_sprites(&app), 2);
+
+        app.world_mut()
+            .get_mut::<ImageFontTypewriter>(entity)
+            .unwrap()
+            .skip_to_end();
+        app.update();
+        assert_eq!(visible_sprites(&app), 4);
+        let events: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<ImageFontTypewriterFinished>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, entity);
+        assert!(app
+            .world()
+            .get::<ImageFontTypewriter>(entity)
+            .unwrap()
+            .is_finished());
+
+        // Changing the text starts over
+        app.world_mut()
+            .get_mut::<ImageFontText>(entity)
+            .unwrap()
+            .text = String::from("EF");
+        app.update();
+        assert_eq!(visible_sprites(&app), 1);
+
+        // Removing the typewriter shows the rest of the text
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<ImageFontTypewriter>();
+        assert_eq!(visible_sprites(&app), 2);
+        app.update();
+        assert_eq!(visible_sprites(&app), 2);
+    }
+}
@@ -0,0 +1,68 @@
+//! This module defines the `ImageFontGlyphEffect` enum, which describes
+//! animations applied to the individual glyphs of text rendered as atlas
+//! sprites.
+//!
+//! Effects are selected for a whole text through the `effects` field of
+//! `ImageFontSpriteText`, and for parts of it through
+//! [`ImageFontSpan::effects`](crate::ImageFontSpan::effects). They are applied
+//! every frame on top of the layout of the text, so they keep animating while
+//! the text doesn't change, and don't affect the positions of other glyphs.
+
+#![expect(
+    clippy::used_underscore_binding,
+    reason = "the code `Reflect` derives for struct variants binds their fields with underscored names"
+)]
+
+use bevy::prelude::*;
+
+/// An animation applied to every glyph of a text, or of a span of it.
+///
+/// Distances are given at the font's native height and are scaled
+/// proportionally based on the current font height, like
+/// [`LetterSpacing`](crate::LetterSpacing). Several effects can be combined,
+/// e.g. to make text wave and cycle through colors at the same time.
+///
+/// Effects only apply to text rendered as atlas sprites; pre-rendered text
+/// ignores them.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[non_exhaustive]
+pub enum ImageFontGlyphEffect {
+    /// Moves the glyphs up and down along a sine wave travelling through the
+    /// text.
+    Wave {
+        /// How far the glyphs move up and down from their place, in pixels.
+        amplitude: f32,
+        /// How many times per second each glyph moves up and down.
+        frequency: f32,
+        /// The number of glyphs from one crest of the wave to the next. If this
+        /// isn't positive, the glyphs aren't moved.
+        wavelength: f32,
+    },
+    /// Moves every glyph by a random offset, which changes `frequency` times
+    /// per second.
+    Shake {
+        /// The maximum distance a glyph is moved from its place along either
+        /// axis, in pixels.
+        magnitude: f32,
+        /// How many times per second the glyphs move to a new random offset.
+        frequency: f32,
+    },
+    /// Replaces the color of the glyphs with a fully saturated hue that cycles
+    /// through the colors of the rainbow. The alpha of the glyphs' color is
+    /// kept.
+    Rainbow {
+        /// How many times per second the colors cycle through the whole
+        /// rainbow.
+        frequency: f32,
+        /// The difference in hue between consecutive glyphs, in degrees.
+        spread: f32,
+    },
+    /// Grows and shrinks every glyph around its center.
+    Pulse {
+        /// How much the glyphs grow and shrink, as a fraction of their size.
+        /// For example, `0.2` scales them between 80% and 120% of their size.
+        amount: f32,
+        /// How many times per second the glyphs grow and shrink.
+        frequency: f32,
+    },
+}
@@ -4,6 +4,8 @@
 // the `docsrs` configuration attribute is defined
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+use std::iter;
+
 use bevy::{
     platform::collections::{HashMap, HashSet},
     prelude::*,
@@ -11,12 +13,20 @@ use bevy::{
 use bevy_image::{Image, ImageSampler};
 use derive_setters::Setters;
 
+mod glyph_effect;
 mod letter_spacing;
+mod line_alignment;
+mod markup;
+mod missing_glyphs;
 #[cfg(any(feature = "rendered", feature = "atlas_sprites"))]
 mod render_context;
 mod scaling_mode;
 
+pub use glyph_effect::*;
 pub use letter_spacing::*;
+pub use line_alignment::*;
+pub use markup::*;
+pub use missing_glyphs::*;
 pub use scaling_mode::*;
 use tracing::info;
 
@@ -36,6 +46,10 @@ pub mod atlas_sprites;
 /// - Loading `ImageFont` assets, which describe the glyph layout.
 /// - Registering the `ImageFont` and `ImageFontText` types for use in your app.
 /// - Marking updated fonts as dirty, ensuring proper re-rendering.
+/// - Reporting characters that are missing from a text's font through the
+///   [`ImageFontMissingGlyphs`] event.
+/// - Generating styled text from markup for entities with an
+///   [`ImageFontMarkup`] component.
 ///
 /// ### Features
 /// The plugin conditionally includes additional functionality based on enabled
@@ -69,7 +83,24 @@ impl Plugin for ImageFontPlugin {
             .init_asset_loader::<loader::ImageFontLoader>()
             .register_type::<ImageFont>()
             .register_type::<ImageFontText>()
-            .add_systems(PostUpdate, sync_texts_with_font_changes);
+            .register_type::<ImageFontSpan>()
+            .register_type::<ImageFontMarkup>()
+            .register_type::<ImageFontMarkupFonts>()
+            .register_type::<ImageFontMarkupIcon>()
+            .init_resource::<ImageFontMarkupFonts>()
+            .add_event::<ImageFontMissingGlyphs>()
+            .add_systems(
+                PostUpdate,
+                (
+                    apply_image_font_markup
+                        .before(sync_texts_with_font_changes)
+                        .in_set(ImageFontSet),
+                    sync_texts_with_font_changes,
+                    report_missing_glyphs
+                        .after(sync_texts_with_font_changes)
+                        .in_set(ImageFontSet),
+                ),
+            );
 
         #[cfg(feature = "rendered")]
         app.add_plugins(rendered::RenderedPlugin);
@@ -109,6 +140,28 @@ pub struct ImageFont {
     /// `nearest`, which scales an image without blurring, keeping the text
     /// crisp and pixellated.
     pub image_sampler: ImageSampler,
+    /// Adjustments to the horizontal distance between specific pairs of
+    /// characters, at the font's native height.
+    ///
+    /// When the character `b` directly follows the character `a`, `b` (and
+    /// everything after it on the same line) is moved right by
+    /// `kerning_pairs[&(a, b)]`. Negative values move characters closer
+    /// together. Pairs that aren't in the map aren't adjusted.
+    pub kerning_pairs: HashMap<(char, char), f32>,
+    /// The character rendered in place of characters that aren't in
+    /// `atlas_character_map`.
+    ///
+    /// If `None`, or if the fallback character isn't in `atlas_character_map`
+    /// itself, unsupported characters are left out of the rendered text.
+    pub fallback_character: Option<char>,
+    /// The vertical metrics of the font's lines, at the font's native height.
+    ///
+    /// If `Some`, the line height is used as the height of the text for
+    /// scaling it to a [`font_height`](ImageFontText::font_height) and for
+    /// anchoring it, so that texts rendered with the same font line up and
+    /// have the same scale regardless of their contents. If `None`, the height
+    /// of the tallest glyph in each text is used instead.
+    pub line_metrics: Option<ImageFontLineMetrics>,
 }
 
 impl ImageFont {
@@ -182,30 +235,149 @@ impl ImageFont {
             textures: texture,
             atlas_character_map,
             image_sampler,
-            // size: default(),
-            // padding: default(),
-            // spacing: default(),
+            kerning_pairs: default(),
+            fallback_character: None,
+            line_metrics: None,
         }
     }
 
-    /// Retrieves references to the font's textures.
+    /// Returns the kerning adjustment between two consecutive characters.
     ///
     /// # Parameters
-    /// - `image_assets`: The asset storage for images.
+    /// - `left`: The character that comes first.
+    /// - `right`: The character directly following `left`.
     ///
     /// # Returns
-    /// A vector of references to the images in use by this font.
-    #[cfg(feature = "rendered")]
-    fn textures<'assets>(&self, image_assets: &'assets Assets<Image>) -> Vec<&'assets Image> {
-        self.textures
-            .iter()
-            .map(|handle| {
-                #[expect(clippy::expect_used, reason = "handle is kept alive by ImageFont")]
-                image_assets
-                    .get(handle)
-                    .expect("handle is kept alive by ImageFont")
-            })
-            .collect()
+    /// The amount, at the font's native height, by which `right` should be
+    /// moved horizontally relative to where it would otherwise be placed, or
+    /// `0.0` if the font doesn't define kerning for this pair.
+    #[must_use]
+    #[inline]
+    pub fn kerning(&self, left: char, right: char) -> f32 {
+        self.kerning_pairs
+            .get(&(left, right))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Adds a texture page to the font at runtime.
+    ///
+    /// # Parameters
+    /// - `texture`: The image containing the page's glyphs.
+    /// - `atlas_layout`: The layout of the glyphs within `texture`.
+    ///
+    /// # Returns
+    /// The index of the page, for use as an
+    /// [`ImageFontCharacter::page_index`]. If the font already has a page with
+    /// the same texture and layout, that page's index is returned instead of
+    /// adding it again.
+    pub fn add_page(
+        &mut self,
+        texture: Handle<Image>,
+        atlas_layout: Handle<TextureAtlasLayout>,
+    ) -> usize {
+        if let Some(page) =
+            self.textures
+                .iter()
+                .zip(&self.atlas_layouts)
+                .position(|(page_texture, page_layout)| {
+                    *page_texture == texture && *page_layout == atlas_layout
+                })
+        {
+            return page;
+        }
+
+        self.textures.push(texture);
+        self.atlas_layouts.push(atlas_layout);
+        self.textures.len() - 1
+    }
+
+    /// Adds a glyph that shows an entry of a texture atlas, such as a
+    /// controller button prompt or a coin icon, to the font.
+    ///
+    /// The glyph takes part in the layout of texts like any other glyph of the
+    /// font, and is scaled along with them. Usually it is added under a
+    /// character from a Unicode private use area (`U+E000` to `U+F8FF`) so it
+    /// doesn't replace one of the font's characters.
+    ///
+    /// # Parameters
+    /// - `character`: The character that is rendered as the glyph. Any glyph
+    ///   the font already has for it is replaced.
+    /// - `texture`: The image containing the texture atlas.
+    /// - `atlas`: The layout of the texture atlas and the index of the entry to
+    ///   show.
+    /// - `baseline`: The distance from the top of the entry to the point that
+    ///   should sit on the font's baseline, as given by its
+    ///   [`line_metrics`](Self::line_metrics). If `None`, or if the font has no
+    ///   line metrics, the glyph is drawn at the top of the line, like glyphs
+    ///   without metrics of their own.
+    ///
+    /// # Returns
+    /// The new character, whose `offsets` and `x_advance` can be adjusted
+    /// further.
+    pub fn insert_atlas_glyph(
+        &mut self,
+        character: char,
+        texture: Handle<Image>,
+        atlas: TextureAtlas,
+        baseline: Option<u32>,
+    ) -> &mut ImageFontCharacter {
+        let page_index = self.add_page(texture, atlas.layout);
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "glyph sizes are small enough to be represented exactly"
+        )]
+        let baseline_offset = self
+            .line_metrics
+            .zip(baseline)
+            .map_or(0., |(line_metrics, glyph_baseline)| {
+                glyph_baseline as f32 - line_metrics.baseline as f32
+            });
+
+        let image_font_character = self.atlas_character_map.entry(character).or_default();
+        *image_font_character = ImageFontCharacter {
+            character_index: atlas.index,
+            page_index,
+            offsets: Vec2::new(0., baseline_offset),
+            x_advance: None,
+        };
+        image_font_character
+    }
+
+    /// Adds a glyph that shows a whole image, such as a controller button
+    /// prompt or a coin icon, to the font.
+    ///
+    /// This works like [`insert_atlas_glyph`](Self::insert_atlas_glyph), with a
+    /// texture atlas layout containing only the image being created for it.
+    ///
+    /// # Parameters
+    /// - `character`: The character that is rendered as the glyph. Any glyph
+    ///   the font already has for it is replaced.
+    /// - `image`: The image to show.
+    /// - `size`: The size of `image`, in pixels.
+    /// - `baseline`: The distance from the top of the image to the point that
+    ///   should sit on the font's baseline. See
+    ///   [`insert_atlas_glyph`](Self::insert_atlas_glyph).
+    /// - `atlas_layouts`: The assets to add the new texture atlas layout to.
+    ///
+    /// # Returns
+    /// The new character, whose `offsets` and `x_advance` can be adjusted
+    /// further.
+    pub fn insert_image_glyph(
+        &mut self,
+        character: char,
+        image: Handle<Image>,
+        size: UVec2,
+        baseline: Option<u32>,
+        atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    ) -> &mut ImageFontCharacter {
+        let mut atlas_layout = TextureAtlasLayout::new_empty(size);
+        let index = atlas_layout.add_texture(URect::from_corners(UVec2::ZERO, size));
+        let atlas = TextureAtlas {
+            layout: atlas_layouts.add(atlas_layout),
+            index,
+        };
+        self.insert_atlas_glyph(character, image, atlas, baseline)
     }
 }
 
@@ -255,6 +427,40 @@ pub struct ImageFontCharacter {
     pub x_advance: Option<f32>,
 }
 
+/// The vertical metrics of the lines of an [`ImageFont`], in pixels at the
+/// font's native height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[non_exhaustive]
+pub struct ImageFontLineMetrics {
+    /// The height of a line of text, measured from its top.
+    pub line_height: u32,
+    /// The distance from the top of a line to its baseline, i.e. the font's
+    /// ascent.
+    pub baseline: u32,
+}
+
+impl ImageFontLineMetrics {
+    /// Creates new `ImageFontLineMetrics`.
+    ///
+    /// # Parameters
+    /// - `line_height`: The height of a line of text.
+    /// - `baseline`: The distance from the top of a line to its baseline.
+    #[must_use]
+    pub const fn new(line_height: u32, baseline: u32) -> Self {
+        Self {
+            line_height,
+            baseline,
+        }
+    }
+
+    /// Returns the font's descent, i.e. the distance from the baseline to the
+    /// bottom of a line. This is `0` if the baseline lies below the line.
+    #[must_use]
+    pub const fn descent(&self) -> u32 {
+        self.line_height.saturating_sub(self.baseline)
+    }
+}
+
 /// Text rendered using an [`ImageFont`].
 #[derive(Debug, Clone, Reflect, Default, Component, Setters)]
 #[setters(into)]
@@ -266,15 +472,146 @@ pub struct ImageFontText {
     /// The handle to the [`ImageFont`] used to render this text. The font's
     /// texture and atlas mapping determine how characters are displayed.
     pub font: Handle<ImageFont>,
+    /// Fonts to take characters from that `font` doesn't contain, in order of
+    /// preference. Each character is rendered using the first font that
+    /// contains it, which allows e.g. combining a small Latin font with a
+    /// separate font for symbols or CJK characters.
+    ///
+    /// Unless `font` has [`line_metrics`](ImageFont::line_metrics), the height
+    /// of the text is determined by the tallest glyph across all fonts, so
+    /// fonts of a similar native height work best together. Kerning
+    /// only applies between characters from the same font. Fallback fonts that
+    /// aren't loaded yet are skipped until they are.
+    pub fallback_fonts: Vec<Handle<ImageFont>>,
     /// If set, overrides the height the font is rendered at. This should be an
     /// integer multiple of the 'native' height if you want pixel accuracy,
     /// but we allow float values for things like animations.
     #[doc(alias = "line_height")]
     pub font_height: Option<f32>,
+    /// Which native height is scaled to `font_height` if `font` has no
+    /// [`line_metrics`](ImageFont::line_metrics). See
+    /// [`FontHeightReference`].
+    pub font_height_reference: FontHeightReference,
+    /// The distance between the tops of two consecutive lines of text, given
+    /// at the font's native height and scaled along with the glyphs. New lines
+    /// are started by `\n` characters in `text`.
+    ///
+    /// If `None`, the line height of `font`'s
+    /// [`line_metrics`](ImageFont::line_metrics) is used, or the height the
+    /// `font_height_reference` refers to if it has none.
+    pub line_height: Option<f32>,
+    /// If set, lines of text wider than this are wrapped onto the next line.
+    /// Lines are broken at whitespace where possible, and between characters
+    /// when a single word doesn't fit.
+    ///
+    /// The width is given in the units the text is displayed at, i.e. after
+    /// scaling the font to `font_height`.
+    pub max_width: Option<f32>,
+    /// How the lines of the text are aligned horizontally relative to each
+    /// other. This is independent of the anchor, which positions the text
+    /// block as a whole.
+    pub line_alignment: LineAlignment,
+    /// Further sections of text that directly follow `text`, each of which
+    /// can be rendered with a different font, color or height, e.g. to
+    /// highlight a keyword in a sentence. See [`ImageFontSpan`].
+    pub spans: Vec<ImageFontSpan>,
 }
 
-/// Marks any text where the underlying [`ImageFont`] asset has changed as
-/// changed, which will cause it to be re-rendered.
+impl ImageFontText {
+    /// Returns whether the text is rendered using the given font, either as
+    /// its main font, as one of its fallback fonts or as the font of one of
+    /// its spans.
+    fn uses_font(&self, id: AssetId<ImageFont>) -> bool {
+        self.font.id() == id
+            || self.fallback_fonts.iter().any(|font| font.id() == id)
+            || self
+                .spans
+                .iter()
+                .filter_map(|span| span.font.as_ref())
+                .any(|font| font.id() == id)
+    }
+
+    /// Collects the fonts this text is rendered with.
+    ///
+    /// # Parameters
+    /// - `image_font`: The loaded asset of the text's `font`.
+    /// - `image_fonts`: The font assets to look up the fallback fonts in.
+    ///
+    /// # Returns
+    /// `image_font`, followed by the fallback fonts that are loaded, in order
+    /// of preference.
+    fn font_chain<'assets>(
+        &self,
+        image_font: &'assets ImageFont,
+        image_fonts: &'assets Assets<ImageFont>,
+    ) -> Vec<&'assets ImageFont> {
+        iter::once(image_font)
+            .chain(
+                self.fallback_fonts
+                    .iter()
+                    .filter_map(|fallback_font| image_fonts.get(fallback_font)),
+            )
+            .collect()
+    }
+}
+
+/// A section of an [`ImageFontText`] that follows its `text`, and that can be
+/// styled differently from the rest of it.
+///
+/// Every property that isn't set is taken from the text the span belongs to.
+/// Spans are laid out on the same lines as the text, and may contain `\n`
+/// characters to start new lines themselves. The lines keep the height
+/// determined by the text's own `font` and `font_height`; glyphs of spans with
+/// a different font or height are aligned to the bottom of their line.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_image_font::{ImageFontSpan, ImageFontText};
+///
+/// let text = ImageFontText::default().text("You found the ").spans(vec![
+///     ImageFontSpan::new("Sword of Truth").color(Color::srgb(1.0, 0.8, 0.0)),
+///     ImageFontSpan::new("!"),
+/// ]);
+/// assert_eq!(text.spans.len(), 2);
+/// ```
+#[derive(Debug, Clone, Reflect, Default, PartialEq, Setters)]
+#[setters(into)]
+#[non_exhaustive]
+pub struct ImageFontSpan {
+    /// The string of text in this span.
+    pub text: String,
+    /// The font to render this span with instead of the text's `font`. The
+    /// text's `fallback_fonts` apply to this font as well. The span is left
+    /// out until the font is loaded.
+    pub font: Option<Handle<ImageFont>>,
+    /// The color to tint this span with instead of the color of the text.
+    pub color: Option<Color>,
+    /// The height to render this span at instead of the text's `font_height`.
+    pub font_height: Option<f32>,
+    /// The effects to animate the glyphs of this span with instead of the
+    /// effects of the text, when it is rendered as atlas sprites. An empty
+    /// list turns the text's effects off for this span.
+    pub effects: Option<Vec<ImageFontGlyphEffect>>,
+}
+
+impl ImageFontSpan {
+    /// Creates a span of text that is styled like the text it belongs to.
+    ///
+    /// # Parameters
+    /// - `text`: The string of text in this span.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..default()
+        }
+    }
+}
+
+/// Marks any text where the underlying [`ImageFont`] asset, or one of its
+/// fallback fonts or the fonts of its spans, has changed as changed, which will
+/// cause it to be re-rendered.
 #[expect(
     private_interfaces,
     reason = "Systems are only `pub` for the sake of allowing dependent crates to use them for ordering"
@@ -291,9 +628,14 @@ pub fn sync_texts_with_font_changes(
     }
 
     // Update query for affected fonts
-    for mut image_font_text in &mut query {
-        if changed_fonts.contains(&image_font_text.font.id()) {
-            image_font_text.set_changed();
+    if !changed_fonts.is_empty() {
+        for mut image_font_text in &mut query {
+            if changed_fonts
+                .iter()
+                .any(|&id| image_font_text.uses_font(id))
+            {
+                image_font_text.set_changed();
+            }
         }
     }
 
@@ -343,19 +685,5 @@ fn extract_asset_id(event: &AssetEvent<ImageFont>) -> Option<AssetId<ImageFont>>
 #[derive(Default, Deref, DerefMut)]
 struct CachedHashSet(HashSet<AssetId<ImageFont>>);
 
-// #[derive(Debug, Clone, Copy, Reflect, Default)]
-// pub struct Padding {
-//     up: u8,
-//     right: u8,
-//     down: u8,
-//     left: u8,
-// }
-
-// #[derive(Debug, Clone, Copy, Reflect, Default)]
-// pub struct Spacing {
-//     horizontal: u8,
-//     vertical: u8,
-// }
-
 #[cfg(test)]
 mod tests;
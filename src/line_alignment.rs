@@ -0,0 +1,100 @@
+//! This module defines the `LineAlignment` enum, which specifies how the lines
+//! of a multi-line text are aligned horizontally relative to each other.
+//!
+//! Line alignment is independent of the text's anchor: the anchor positions
+//! the text block as a whole, while the line alignment positions each line
+//! within that block. The block is as wide as its widest line, so a text
+//! consisting of a single line looks the same regardless of its alignment.
+//!
+//! The available options are:
+//! - `Left`: Lines start at the left edge of the block. This is the default.
+//! - `Center`: Lines are centered within the block.
+//! - `Right`: Lines end at the right edge of the block.
+//! - `Justified`: Lines are stretched to fill the block by widening the
+//!   whitespace between words.
+
+use bevy::prelude::*;
+
+/// Determines how each line of a text is aligned horizontally within the text
+/// block.
+///
+/// The text block is as wide as its widest line; narrower lines are moved or
+/// stretched to line up with it according to the selected variant. Where the
+/// block itself ends up is controlled by the text's anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum LineAlignment {
+    /// Aligns every line to the left edge of the text block.
+    ///
+    /// This is the default line alignment.
+    #[default]
+    Left,
+
+    /// Centers every line horizontally within the text block.
+    Center,
+
+    /// Aligns every line to the right edge of the text block.
+    Right,
+
+    /// Stretches every line to the full width of the text block by evenly
+    /// distributing the remaining space over the whitespace between words.
+    ///
+    /// The last line of each paragraph, i.e. a line followed by a `\n` or the
+    /// end of the text, is left-aligned instead, as are lines without any
+    /// whitespace to widen.
+    Justified,
+}
+
+impl LineAlignment {
+    /// Returns how far a line has to be moved to the right to be aligned
+    /// within a text block, as a fraction of the space the line leaves empty.
+    ///
+    /// # Returns
+    /// `0.0` for left alignment, `0.5` for centered and `1.0` for right
+    /// alignment. Justified lines are widened instead of moved, so this is
+    /// `0.0` for them.
+    #[must_use]
+    pub fn offset_factor(self) -> f32 {
+        match self {
+            LineAlignment::Left | LineAlignment::Justified => 0.0,
+            LineAlignment::Center => 0.5,
+            LineAlignment::Right => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_eq::assert_float_eq;
+
+    use super::*;
+    use crate::tests::utils::COMPARISON_TOLERANCE;
+
+    #[test]
+    fn default_is_left() {
+        assert_eq!(LineAlignment::default(), LineAlignment::Left);
+    }
+
+    #[test]
+    fn offset_factor_gives_expected_value() {
+        assert_float_eq!(
+            LineAlignment::Left.offset_factor(),
+            0.0,
+            abs <= COMPARISON_TOLERANCE
+        );
+        assert_float_eq!(
+            LineAlignment::Center.offset_factor(),
+            0.5,
+            abs <= COMPARISON_TOLERANCE
+        );
+        assert_float_eq!(
+            LineAlignment::Right.offset_factor(),
+            1.0,
+            abs <= COMPARISON_TOLERANCE
+        );
+        assert_float_eq!(
+            LineAlignment::Justified.offset_factor(),
+            0.0,
+            abs <= COMPARISON_TOLERANCE
+        );
+    }
+}
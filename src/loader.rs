@@ -3,19 +3,21 @@
 #![expect(clippy::absolute_paths, reason = "false positives")]
 
 use std::io::Error as IoError;
+use std::iter;
 
 use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext, LoadDirectError},
+    color::ColorToPacked as _,
     platform::collections::HashMap,
     prelude::*,
 };
-use bevy_image::{Image, ImageSampler, ImageSamplerDescriptor};
+use bevy_image::{Image, ImageSampler, ImageSamplerDescriptor, TextureAccessError};
 use camino::{FromPathError, Utf8Path, Utf8PathBuf};
 use ron::de::SpannedError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{ImageFont, ImageFontCharacter};
+use crate::{ImageFont, ImageFontCharacter, ImageFontLineMetrics};
 
 #[cfg(feature = "bmf")]
 mod bmf;
@@ -49,6 +51,47 @@ pub enum ImageFontLayout {
     /// ```
     Automatic(String),
 
+    /// Like [`Automatic`](ImageFontLayout::Automatic), but for images whose
+    /// grid cells don't tile the image exactly: the grid may be surrounded by
+    /// a margin, its cells may be separated by spacing, and each glyph may be
+    /// surrounded by padding within its cell. All three are given in pixels
+    /// and default to `0` when omitted, as do their individual sides.
+    ///
+    /// ```rust
+    /// # use bevy_image_font::loader::*;
+    /// let s = r#"
+    /// AutomaticGrid(
+    ///     characters: "ABCD\nEFGH",
+    ///     margin: (top: 1, right: 1, bottom: 1, left: 1),
+    ///     padding: (left: 1, right: 1),
+    ///     spacing: (horizontal: 1, vertical: 1),
+    /// )
+    /// "#;
+    /// ron::from_str::<ImageFontLayout>(s).unwrap();
+    /// ```
+    ///
+    /// For an image of width `w`, the width of each cell is
+    /// `(w - margin.left - margin.right - (columns - 1) * spacing.horizontal)
+    /// / columns`, which must be a whole number; the same goes for heights.
+    /// The glyph is the part of the cell not covered by `padding`.
+    AutomaticGrid {
+        /// The characters of the grid, interpreted like the string of an
+        /// [`Automatic`](ImageFontLayout::Automatic) layout.
+        characters: String,
+
+        /// The space between the edges of the image and the outermost cells.
+        #[serde(default)]
+        margin: Padding,
+
+        /// The space between the edges of each cell and its glyph.
+        #[serde(default)]
+        padding: Padding,
+
+        /// The space between neighboring cells.
+        #[serde(default)]
+        spacing: Spacing,
+    },
+
     /// Manually specifies the top-left position of each character, where each
     /// character has the same size. When writing this in RON, the syntax
     /// will look like
@@ -90,6 +133,39 @@ pub enum ImageFontLayout {
     /// ron::from_str::<ImageFontLayout>(s).unwrap();
     /// ```
     Manual(HashMap<char, URect>),
+
+    /// Discovers the bounds of each character by scanning the image for lines
+    /// of a separator color, as used by many pixel fonts made for other
+    /// toolchains. When writing this in RON, the syntax will look like
+    ///
+    /// ```rust
+    /// # use bevy_image_font::loader::*;
+    /// let s = r#"
+    /// Separated(
+    ///     characters: "ABCDEFGH",
+    ///     separator: (255, 0, 255, 255),
+    /// )
+    /// "#;
+    /// ron::from_str::<ImageFontLayout>(s).unwrap();
+    /// ```
+    ///
+    /// The image is first split into rows of glyphs at every pixel row that
+    /// consists entirely of the separator color. Each row of glyphs is then
+    /// split into glyphs at every pixel column that is entirely of the
+    /// separator color within that row. The glyphs found this way are assigned
+    /// the characters of `characters` in reading order; line breaks in
+    /// `characters` are ignored, so long strings can be wrapped to match the
+    /// image.
+    Separated {
+        /// The characters of the font, in the order their glyphs appear in the
+        /// image.
+        characters: String,
+
+        /// The color of the separator lines, as 8-bit sRGB red, green, blue
+        /// and alpha components. Only pixels of exactly this color count as
+        /// separators.
+        separator: [u8; 4],
+    },
 }
 
 /// Errors that can show up during validation.
@@ -98,11 +174,12 @@ pub enum ImageFontLayout {
 pub enum ImageFontLayoutValidationError {
     /// The image width does not evenly divide the character count per line.
     ///
-    /// This error occurs when the width of the provided image is not a multiple
-    /// of the number of characters per line specified in the layout.
+    /// This error occurs when the width of the provided image, minus any
+    /// margins and spacing of the grid, is not a multiple of the number of
+    /// characters per line specified in the layout.
     #[error(
-        "Image width {width} is not an exact multiple of per-line character count \
-    {per_line_character_count}."
+        "Image width {width} (minus margins and spacing) is not an exact multiple of per-line \
+        character count {per_line_character_count}."
     )]
     InvalidImageWidth {
         /// The width of the image being validated.
@@ -113,9 +190,13 @@ pub enum ImageFontLayoutValidationError {
 
     /// The image height does not evenly divide the number of lines.
     ///
-    /// This error occurs when the height of the provided image is not a
-    /// multiple of the number of lines in the layout.
-    #[error("Image height {height} is not an exact multiple of line count {line_count}.")]
+    /// This error occurs when the height of the provided image, minus any
+    /// margins and spacing of the grid, is not a multiple of the number of
+    /// lines in the layout.
+    #[error(
+        "Image height {height} (minus margins and spacing) is not an exact multiple of line \
+        count {line_count}."
+    )]
     InvalidImageHeight {
         /// The height of the image being validated.
         height: u32,
@@ -123,7 +204,18 @@ pub enum ImageFontLayoutValidationError {
         line_count: u32,
     },
 
-    /// A repeated character was found in an `Automatic` layout string.
+    /// The padding of an `AutomaticGrid` layout is larger than its cells.
+    ///
+    /// This error occurs when the padding on opposite sides of a cell adds up
+    /// to more than the cell's width or height, leaving no room for the glyph.
+    #[error("Padding doesn't fit in grid cells of size {cell_size}.")]
+    PaddingExceedsCell {
+        /// The size of the grid's cells, including padding.
+        cell_size: UVec2,
+    },
+
+    /// A repeated character was found in an `Automatic`, `AutomaticGrid` or
+    /// `Separated` layout string.
     ///
     /// This error occurs when the same character appears multiple times in the
     /// layout string, leading to conflicting placement definitions.
@@ -141,81 +233,68 @@ pub enum ImageFontLayoutValidationError {
         /// The character that was repeated in the layout string.
         character: char,
     },
+
+    /// A character is placed on more than one page of a multi-image font.
+    ///
+    /// This error occurs when the layouts of two of the font's images both
+    /// contain the same character.
+    #[error("The character '{character}' appears on more than one page; again on page {page}.")]
+    CharacterOnSeveralPages {
+        /// The index of the page the character appears on for the second time.
+        /// The font's main image is page `0`, and its additional pages follow.
+        page: usize,
+        /// The character that appears on several pages.
+        character: char,
+    },
+
+    /// Glyph metrics were given for a character that isn't part of the font.
+    #[error("Glyph metrics were given for the character '{character}', which isn't in the font.")]
+    MetricsForMissingCharacter {
+        /// The character the metrics were given for.
+        character: char,
+    },
+
+    /// The number of glyphs found in the image of a `Separated` layout doesn't
+    /// match the number of characters in its layout string.
+    #[error(
+        "Found {glyph_count} glyphs between separators, but the layout string has \
+        {character_count} characters."
+    )]
+    SeparatedGlyphCountMismatch {
+        /// The number of glyphs found in the image.
+        glyph_count: usize,
+        /// The number of characters in the layout string.
+        character_count: usize,
+    },
+
+    /// The pixels of the image couldn't be read to find the glyphs of a
+    /// `Separated` layout. This may occur if the image uses a texture format
+    /// that doesn't support reading individual pixels.
+    #[error("Couldn't read the image's pixels to find separators: {0}")]
+    UnreadableImage(TextureAccessError),
 }
 
 impl ImageFontLayout {
-    /// Given the image size, returns a map from each codepoint to its location.
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "while usize can hold more data than u32, we're working on a number here that \
-        should be substantially smaller than even u32's capacity"
-    )]
+    /// Given the image, returns a map from each codepoint to its location.
     fn into_character_rect_map(
         self,
-        size: UVec2,
+        image: &Image,
     ) -> Result<HashMap<char, URect>, ImageFontLayoutValidationError> {
+        let size = image.size();
         match self {
-            ImageFontLayout::Automatic(str) => {
-                // trim() removes whitespace, which is not what we want!
-                let str = str
-                    .trim_start_matches(['\r', '\n'])
-                    .trim_end_matches(['\r', '\n']);
-                #[expect(
-                    clippy::expect_used,
-                    reason = "this intentionally panics on an empty string. Should never happen as \
-                    the ImageFontLayout should always have been validated before this method gets \
-                    called"
-                )]
-                let max_chars_per_line = str
-                    .lines()
-                    // important: *not* l.len()
-                    .map(|line| line.chars().count())
-                    .max()
-                    .expect("can't create character map from an empty string")
-                    as u32;
-
-                if size.x % max_chars_per_line != 0 {
-                    return Err(ImageFontLayoutValidationError::InvalidImageWidth {
-                        width: size.x,
-                        per_line_character_count: max_chars_per_line,
-                    });
-                }
-                let line_count = str.lines().count() as u32;
-                if size.y % line_count != 0 {
-                    return Err(ImageFontLayoutValidationError::InvalidImageHeight {
-                        height: size.y,
-                        line_count,
-                    });
-                }
-
-                let mut rect_map =
-                    HashMap::with_capacity((max_chars_per_line * line_count) as usize);
-
-                let rect_width = size.x / max_chars_per_line;
-                let rect_height = size.y / line_count;
-
-                for (row, line) in str.lines().enumerate() {
-                    for (column, character) in line.chars().enumerate() {
-                        let rect = URect::new(
-                            rect_width * column as u32,
-                            rect_height * row as u32,
-                            rect_width * (column + 1) as u32,
-                            rect_height * (row + 1) as u32,
-                        );
-                        if rect_map.insert(character, rect).is_some() {
-                            return Err(
-                                ImageFontLayoutValidationError::AutomaticRepeatedCharacter {
-                                    row,
-                                    column,
-                                    character,
-                                },
-                            );
-                        }
-                    }
-                }
-
-                Ok(rect_map)
-            }
+            ImageFontLayout::Automatic(characters) => automatic_character_rect_map(
+                &characters,
+                size,
+                Padding::default(),
+                Padding::default(),
+                Spacing::default(),
+            ),
+            ImageFontLayout::AutomaticGrid {
+                characters,
+                margin,
+                padding,
+                spacing,
+            } => automatic_character_rect_map(&characters, size, margin, padding, spacing),
             ImageFontLayout::ManualMonospace { size, coords } => Ok(coords
                 .into_iter()
                 .map(|(character, top_left)| {
@@ -223,10 +302,466 @@ impl ImageFontLayout {
                 })
                 .collect()),
             ImageFontLayout::Manual(urect_map) => Ok(urect_map),
+            ImageFontLayout::Separated {
+                characters,
+                separator,
+            } => separated_character_rect_map(&characters, separator, image),
+        }
+    }
+}
+
+/// Slices up an image into a grid of cells according to an `Automatic` or
+/// `AutomaticGrid` layout.
+///
+/// # Parameters
+/// - `characters`: The layout string. Leading and trailing newlines are
+///   stripped, but spaces are not.
+/// - `size`: The size of the image.
+/// - `margin`: The space between the edges of the image and the grid.
+/// - `padding`: The space between the edges of each cell and its glyph.
+/// - `spacing`: The space between neighboring cells.
+///
+/// # Returns
+/// A map from each character to the bounds of its glyph.
+///
+/// # Errors
+/// Returns an [`ImageFontLayoutValidationError`] if the image can't be evenly
+/// divided into cells, the padding doesn't fit in the cells, or a character
+/// appears more than once.
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "while usize can hold more data than u32, we're working on a number here that \
+    should be substantially smaller than even u32's capacity"
+)]
+fn automatic_character_rect_map(
+    characters: &str,
+    size: UVec2,
+    margin: Padding,
+    padding: Padding,
+    spacing: Spacing,
+) -> Result<HashMap<char, URect>, ImageFontLayoutValidationError> {
+    // trim() removes whitespace, which is not what we want!
+    let characters = characters
+        .trim_start_matches(['\r', '\n'])
+        .trim_end_matches(['\r', '\n']);
+    #[expect(
+        clippy::expect_used,
+        reason = "this intentionally panics on an empty string. Should never happen as \
+        the ImageFontLayout should always have been validated before this method gets \
+        called"
+    )]
+    let max_chars_per_line = characters
+        .lines()
+        // important: *not* l.len()
+        .map(|line| line.chars().count())
+        .max()
+        .expect("can't create character map from an empty string")
+        as u32;
+
+    let Some(cell_width) = grid_cell_length(
+        size.x,
+        max_chars_per_line,
+        margin.left + margin.right,
+        spacing.horizontal,
+    ) else {
+        return Err(ImageFontLayoutValidationError::InvalidImageWidth {
+            width: size.x,
+            per_line_character_count: max_chars_per_line,
+        });
+    };
+    let line_count = characters.lines().count() as u32;
+    let Some(cell_height) = grid_cell_length(
+        size.y,
+        line_count,
+        margin.top + margin.bottom,
+        spacing.vertical,
+    ) else {
+        return Err(ImageFontLayoutValidationError::InvalidImageHeight {
+            height: size.y,
+            line_count,
+        });
+    };
+
+    let cell_size = UVec2::new(cell_width, cell_height);
+    let (Some(glyph_width), Some(glyph_height)) = (
+        cell_width.checked_sub(padding.left + padding.right),
+        cell_height.checked_sub(padding.top + padding.bottom),
+    ) else {
+        return Err(ImageFontLayoutValidationError::PaddingExceedsCell { cell_size });
+    };
+
+    let mut rect_map = HashMap::with_capacity((max_chars_per_line * line_count) as usize);
+
+    let cell_stride = cell_size + UVec2::new(spacing.horizontal, spacing.vertical);
+    let glyph_origin = UVec2::new(margin.left + padding.left, margin.top + padding.top);
+    let glyph_size = UVec2::new(glyph_width, glyph_height);
+
+    for (row, line) in characters.lines().enumerate() {
+        for (column, character) in line.chars().enumerate() {
+            let min = glyph_origin + cell_stride * UVec2::new(column as u32, row as u32);
+            let rect = URect::from_corners(min, min + glyph_size);
+            if rect_map.insert(character, rect).is_some() {
+                return Err(ImageFontLayoutValidationError::AutomaticRepeatedCharacter {
+                    row,
+                    column,
+                    character,
+                });
+            }
+        }
+    }
+
+    Ok(rect_map)
+}
+
+/// Finds the glyphs of a `Separated` layout and assigns them their characters.
+///
+/// # Parameters
+/// - `characters`: The layout string. Line breaks are ignored.
+/// - `separator`: The 8-bit sRGBA color of the separator lines.
+/// - `image`: The image to scan for glyphs.
+///
+/// # Returns
+/// A map from each character to the bounds of its glyph.
+///
+/// # Errors
+/// Returns an [`ImageFontLayoutValidationError`] if the pixels of `image`
+/// can't be read, the number of glyphs doesn't match the number of characters,
+/// or a character appears more than once.
+fn separated_character_rect_map(
+    characters: &str,
+    separator: [u8; 4],
+    image: &Image,
+) -> Result<HashMap<char, URect>, ImageFontLayoutValidationError> {
+    let is_separator = |x, y| -> Result<bool, TextureAccessError> {
+        Ok(image.get_color_at(x, y)?.to_srgba().to_u8_array() == separator)
+    };
+
+    let size = image.size();
+    let mut glyph_rects = Vec::new();
+    let rows = non_separator_runs(size.y, |y| {
+        (0..size.x).try_fold(true, |all, x| Ok(all && is_separator(x, y)?))
+    })
+    .map_err(ImageFontLayoutValidationError::UnreadableImage)?;
+    for (min_y, max_y) in rows {
+        let columns = non_separator_runs(size.x, |x| {
+            (min_y..max_y).try_fold(true, |all, y| Ok(all && is_separator(x, y)?))
+        })
+        .map_err(ImageFontLayoutValidationError::UnreadableImage)?;
+        glyph_rects.extend(
+            columns
+                .into_iter()
+                .map(|(min_x, max_x)| URect::new(min_x, min_y, max_x, max_y)),
+        );
+    }
+
+    let character_count = characters
+        .chars()
+        .filter(|character| !matches!(character, '\r' | '\n'))
+        .count();
+    if glyph_rects.len() != character_count {
+        return Err(
+            ImageFontLayoutValidationError::SeparatedGlyphCountMismatch {
+                glyph_count: glyph_rects.len(),
+                character_count,
+            },
+        );
+    }
+
+    let mut rect_map = HashMap::with_capacity(character_count);
+    let positioned_characters = characters.lines().enumerate().flat_map(|(row, line)| {
+        line.chars()
+            .enumerate()
+            .map(move |(column, character)| (row, column, character))
+    });
+    for ((row, column, character), rect) in positioned_characters.zip(glyph_rects) {
+        if rect_map.insert(character, rect).is_some() {
+            return Err(ImageFontLayoutValidationError::AutomaticRepeatedCharacter {
+                row,
+                column,
+                character,
+            });
+        }
+    }
+
+    Ok(rect_map)
+}
+
+/// Finds the runs of consecutive non-separator lines along one axis of an
+/// image.
+///
+/// # Parameters
+/// - `length`: The number of lines along the axis.
+/// - `is_separator`: Determines whether the line at the given position consists
+///   entirely of the separator color.
+///
+/// # Returns
+/// The start (inclusive) and end (exclusive) of each run, in order.
+///
+/// # Errors
+/// Returns any error returned by `is_separator`.
+fn non_separator_runs(
+    length: u32,
+    mut is_separator: impl FnMut(u32) -> Result<bool, TextureAccessError>,
+) -> Result<Vec<(u32, u32)>, TextureAccessError> {
+    let mut runs = Vec::new();
+    let mut run_start = None;
+    for position in 0..length {
+        match (is_separator(position)?, run_start) {
+            (true, Some(start)) => {
+                runs.push((start, position));
+                run_start = None;
+            }
+            (false, None) => run_start = Some(position),
+            (true, None) | (false, Some(_)) => {}
+        }
+    }
+    if let Some(start) = run_start {
+        runs.push((start, length));
+    }
+    Ok(runs)
+}
+
+/// Determines the length of the cells along one axis of a grid.
+///
+/// # Parameters
+/// - `length`: The length of the image along the axis.
+/// - `count`: The number of cells along the axis.
+/// - `margins`: The sum of the margins at both ends of the axis.
+/// - `spacing`: The space between neighboring cells.
+///
+/// # Returns
+/// The length of each cell, or `None` if the cells don't fit into the image
+/// exactly.
+fn grid_cell_length(length: u32, count: u32, margins: u32, spacing: u32) -> Option<u32> {
+    let available = length
+        .checked_sub(margins)?
+        .checked_sub(spacing.checked_mul(count.saturating_sub(1))?)?;
+    available.is_multiple_of(count).then_some(available / count)
+}
+
+/// Space around the four sides of a rectangle, in pixels. Used for the margin
+/// and padding of [`ImageFontLayout::AutomaticGrid`] layouts. When writing this
+/// in RON, any of the sides may be left out, in which case they are `0`:
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = "(top: 2, left: 1)";
+/// ron::from_str::<Padding>(s).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct Padding {
+    /// The space above the rectangle.
+    pub top: u32,
+    /// The space to the right of the rectangle.
+    pub right: u32,
+    /// The space below the rectangle.
+    pub bottom: u32,
+    /// The space to the left of the rectangle.
+    pub left: u32,
+}
+
+impl Padding {
+    /// Creates a new `Padding` with the given space on each side.
+    #[must_use]
+    pub const fn new(top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Creates a new `Padding` with the same space on all four sides.
+    #[must_use]
+    pub const fn uniform(space: u32) -> Self {
+        Self::new(space, space, space, space)
+    }
+}
+
+/// Space between neighboring cells of an [`ImageFontLayout::AutomaticGrid`]
+/// layout, in pixels. When writing this in RON, either direction may be left
+/// out, in which case it is `0`:
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = "(horizontal: 1)";
+/// ron::from_str::<Spacing>(s).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct Spacing {
+    /// The space between neighboring columns.
+    pub horizontal: u32,
+    /// The space between neighboring rows.
+    pub vertical: u32,
+}
+
+impl Spacing {
+    /// Creates a new `Spacing` with the given space between columns and rows.
+    #[must_use]
+    pub const fn new(horizontal: u32, vertical: u32) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+/// Placement information for a single glyph of an image font. When writing
+/// this in RON, any of the fields may be left out:
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = "(offset: (1, 0), x_advance: Some(6), baseline: Some(5))";
+/// ron::from_str::<GlyphMetrics>(s).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct GlyphMetrics {
+    /// How far to move the glyph from where it would otherwise be drawn, in
+    /// pixels. Positive values move the glyph right and down, like image
+    /// coordinates do.
+    pub offset: Vec2,
+
+    /// How far to advance the position of the next character after this one,
+    /// in pixels. If `None`, the glyph's width is used.
+    pub x_advance: Option<f32>,
+
+    /// The distance from the top of the glyph's rectangle to the baseline, in
+    /// pixels. If given, the glyph is moved vertically so that this row lines
+    /// up with the font's baseline; anything below it, like the tail of a `g`,
+    /// hangs below the baseline. If `None`, the glyph is drawn at the top of
+    /// its line.
+    pub baseline: Option<u32>,
+}
+
+impl GlyphMetrics {
+    /// Creates new `GlyphMetrics`.
+    ///
+    /// # Parameters
+    /// - `offset`: How far to move the glyph right and down, in pixels.
+    /// - `x_advance`: How far to advance to the next character, if not by the
+    ///   glyph's width.
+    /// - `baseline`: The distance from the top of the glyph to the baseline, if
+    ///   the glyph should be aligned to the font's baseline.
+    #[must_use]
+    pub const fn new(offset: Vec2, x_advance: Option<f32>, baseline: Option<u32>) -> Self {
+        Self {
+            offset,
+            x_advance,
+            baseline,
         }
     }
 }
 
+/// Settings for shrinking the glyphs of a font to the part of their rectangles
+/// that is actually drawn on, so that a proportional font drawn on a grid
+/// doesn't render with large gaps between narrow characters. When writing this
+/// in RON, either field may be left out, in which case it is `0`:
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = "(min_width: 3, alpha_threshold: 0.5)";
+/// ron::from_str::<GlyphTrimming>(s).unwrap();
+/// ```
+///
+/// Glyphs are only trimmed horizontally, from their left and right edges,
+/// keeping all glyphs as tall as their rectangles so that they stay aligned on
+/// a common baseline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct GlyphTrimming {
+    /// The minimum width of a trimmed glyph, in pixels. Glyphs with no visible
+    /// pixels at all, such as the space character, are this wide, starting at
+    /// the left edge of their rectangle. Narrower glyphs are widened to the
+    /// right, but never beyond their rectangle.
+    ///
+    /// If this is `0`, glyphs with no visible pixels keep the full width of
+    /// their rectangle instead, so that they don't disappear from the text.
+    pub min_width: u32,
+
+    /// The alpha value, between `0.0` and `1.0`, that a pixel must exceed to
+    /// count as visible.
+    pub alpha_threshold: f32,
+}
+
+impl GlyphTrimming {
+    /// Creates new `GlyphTrimming` settings.
+    ///
+    /// # Parameters
+    /// - `min_width`: The minimum width of a trimmed glyph, in pixels.
+    /// - `alpha_threshold`: The alpha value a pixel must exceed to count as
+    ///   visible.
+    #[must_use]
+    pub const fn new(min_width: u32, alpha_threshold: f32) -> Self {
+        Self {
+            min_width,
+            alpha_threshold,
+        }
+    }
+
+    /// Trims every glyph rectangle of a texture atlas layout.
+    ///
+    /// # Parameters
+    /// - `layout`: The layout whose rectangles to trim.
+    /// - `image`: The image the layout's rectangles lie in.
+    ///
+    /// # Errors
+    /// Returns a [`TextureAccessError`] if the pixels of `image` can't be read.
+    fn trim_atlas_layout(
+        self,
+        layout: &mut TextureAtlasLayout,
+        image: &Image,
+    ) -> Result<(), TextureAccessError> {
+        for rect in &mut layout.textures {
+            *rect = self.trimmed_rect(*rect, image)?;
+        }
+        Ok(())
+    }
+
+    /// Shrinks a glyph rectangle to the columns containing visible pixels,
+    /// respecting `min_width`. Rectangles without visible pixels keep their
+    /// full width if `min_width` is `0`.
+    ///
+    /// # Parameters
+    /// - `rect`: The glyph's rectangle.
+    /// - `image`: The image the rectangle lies in.
+    ///
+    /// # Returns
+    /// The trimmed rectangle, which is never larger than `rect`.
+    ///
+    /// # Errors
+    /// Returns a [`TextureAccessError`] if the pixels of `image` can't be read.
+    fn trimmed_rect(self, rect: URect, image: &Image) -> Result<URect, TextureAccessError> {
+        let mut visible_columns = None;
+        for x in rect.min.x..rect.max.x {
+            for y in rect.min.y..rect.max.y {
+                if image.get_color_at(x, y)?.alpha() > self.alpha_threshold {
+                    visible_columns = match visible_columns {
+                        None => Some((x, x + 1)),
+                        Some((min_x, _)) => Some((min_x, x + 1)),
+                    };
+                    break;
+                }
+            }
+        }
+
+        let (min_x, max_x) = match visible_columns {
+            Some(visible_columns) => visible_columns,
+            None if self.min_width == 0 => (rect.min.x, rect.max.x),
+            None => (rect.min.x, rect.min.x),
+        };
+        let max_x = max_x.max(min_x + self.min_width).min(rect.max.x);
+        Ok(URect::new(min_x, rect.min.y, max_x, rect.max.y))
+    }
+}
+
 /// On-disk representation of an [`ImageFont`], optimized to make it easy for
 /// humans to write these. See the docs for [`ImageFontLayout`]'s variants for
 /// information on how to write the syntax, or [the example font's RON asset].
@@ -243,6 +778,278 @@ pub struct ImageFontDescriptor {
     /// regions within the image. This can use any of the variants provided
     /// by [`ImageFontLayout`], allowing flexible configuration.
     layout: ImageFontLayout,
+
+    /// Further images containing font glyphs, each with its own layout. See
+    /// [`ImageFontPage`] for the syntax. These become texture pages `1`, `2`
+    /// and so on of the font, in order, with `image` being page `0`. If
+    /// omitted, the font consists of `image` alone.
+    #[serde(default)]
+    additional_pages: Vec<ImageFontPage>,
+
+    /// Whether to shrink the glyphs to the columns of their rectangles that
+    /// contain visible pixels, e.g. `Some((min_width: 3))`, turning a font laid
+    /// out on a grid into a variable-width font. See [`GlyphTrimming`]. If
+    /// omitted, glyphs keep the rectangles given by their layout.
+    #[serde(default)]
+    trim_glyphs: Option<GlyphTrimming>,
+
+    /// Optional placement information for individual glyphs, such as
+    /// descenders hanging below the baseline. See [`GlyphMetrics`] for the
+    /// syntax. Glyphs that aren't listed are drawn at the top of their line,
+    /// advancing by their width.
+    #[serde(default)]
+    glyph_metrics: HashMap<char, GlyphMetrics>,
+
+    /// The distance from the top of a line to the baseline, in pixels. Glyphs
+    /// with a [`baseline`](GlyphMetrics::baseline) in their metrics are moved
+    /// up or down so that their baseline is at this height. If omitted, this
+    /// is the height of the tallest glyph without a `baseline`, i.e. glyphs
+    /// that don't specify one are assumed to sit on the baseline.
+    #[serde(default)]
+    baseline: Option<u32>,
+
+    /// The height of a line of text, in pixels. If given, the font's
+    /// [`line_metrics`](ImageFont::line_metrics) are set from this and the
+    /// `baseline`, giving all texts rendered with the font the same height
+    /// regardless of their contents. If omitted, the height of each text is
+    /// that of its tallest glyph.
+    #[serde(default)]
+    line_height: Option<u32>,
+
+    /// Optional adjustments to the spacing between specific characters. See
+    /// [`ImageFontKerning`] for the syntax. If omitted, no kerning is applied.
+    #[serde(default)]
+    kerning: ImageFontKerning,
+
+    /// The character to render in place of characters the font doesn't
+    /// contain, e.g. `Some('?')`. It must be part of the layout. If omitted,
+    /// unsupported characters are left out of rendered text.
+    #[serde(default)]
+    fallback_character: Option<char>,
+}
+
+/// An additional image of a font whose glyphs are spread over several images,
+/// along with the layout of the glyphs within it. When writing this in RON,
+/// the syntax will look like
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = r#"
+/// (
+///     image: "symbols.png",
+///     layout: Automatic("★♥♦♣♠"),
+/// )
+/// "#;
+/// ron::from_str::<ImageFontPage>(s).unwrap();
+/// ```
+///
+/// A character may only appear on a single page of a font.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImageFontPage {
+    /// The path to the image file containing the page's glyphs, relative to
+    /// the RON file.
+    image: Utf8PathBuf,
+
+    /// The layout description of the page, specifying how characters map to
+    /// regions within its image.
+    layout: ImageFontLayout,
+}
+
+impl ImageFontPage {
+    /// Creates a new `ImageFontPage` instance with the provided image path and
+    /// layout, performing validation to ensure the page is valid.
+    ///
+    /// # Parameters
+    /// - `image`: The path to the image file containing the page's glyphs,
+    ///   relative to the RON file that described the font.
+    /// - `layout`: The layout description of the page. See [`ImageFontLayout`]
+    ///   for more details about the available layout configurations.
+    ///
+    /// # Returns
+    /// A new `ImageFontPage` instance if validation succeeds.
+    ///
+    /// # Errors
+    /// Returns an [`ImageFontDescriptorValidationError`] if the provided values
+    /// do not pass validation.
+    pub fn new(
+        image: Utf8PathBuf,
+        layout: ImageFontLayout,
+    ) -> Result<Self, ImageFontDescriptorValidationError> {
+        let value = Self { image, layout };
+        value.validate()?;
+        Ok(value)
+    }
+
+    /// Validates that the image path and, for `Automatic` layouts, the layout
+    /// string aren't empty.
+    ///
+    /// # Errors
+    ///   - `ImageFontLoadError::EmptyImagePath` if the `image` path is empty.
+    ///   - `ImageFontLoadError::EmptyLayoutString` if the `layout` string for
+    ///     `Automatic` is empty.
+    fn validate(&self) -> Result<(), ImageFontDescriptorValidationError> {
+        validate_page(&self.image, &self.layout)
+    }
+
+    /// Gets the path to the image file containing the page's glyphs.
+    ///
+    /// # Returns
+    /// A reference to the `Utf8PathBuf` containing the image file path.
+    #[must_use]
+    pub fn image(&self) -> &Utf8Path {
+        &self.image
+    }
+
+    /// Gets the layout description of the page.
+    ///
+    /// # Returns
+    /// A reference to the `ImageFontLayout` describing the page layout.
+    #[must_use]
+    pub fn layout(&self) -> &ImageFontLayout {
+        &self.layout
+    }
+}
+
+/// Validates the image path and layout of a single page of a font.
+///
+/// # Errors
+///   - `ImageFontLoadError::EmptyImagePath` if `image` is empty.
+///   - `ImageFontLoadError::EmptyLayoutString` if `layout` is an `Automatic`
+///     layout with an empty string.
+fn validate_page(
+    image: &Utf8Path,
+    layout: &ImageFontLayout,
+) -> Result<(), ImageFontDescriptorValidationError> {
+    if image.as_str().trim().is_empty() {
+        return Err(ImageFontDescriptorValidationError::EmptyImagePath);
+    }
+    if matches!(
+        *layout,
+        ImageFontLayout::Automatic(ref characters)
+            | ImageFontLayout::AutomaticGrid { ref characters, .. }
+            | ImageFontLayout::Separated { ref characters, .. }
+            if characters.trim().is_empty()
+    ) {
+        return Err(ImageFontDescriptorValidationError::EmptyLayoutString);
+    }
+    Ok(())
+}
+
+/// Kerning information for an image font, i.e. adjustments to the horizontal
+/// distance between specific pairs of characters.
+///
+/// Kerning can be given for individual pairs of characters, or for whole
+/// classes of characters at once. Amounts are in pixels at the font's native
+/// size; negative values move characters closer together. When writing this
+/// in RON, the syntax will look like
+///
+/// ```rust
+/// # use bevy_image_font::loader::*;
+/// let s = r#"
+/// (
+///     // Each key is exactly two characters: the left and right character
+///     pairs: {
+///         "AV": -1,
+///         "To": -2,
+///     },
+///     // Applies to every combination of a character in `left` followed by a
+///     // character in `right`
+///     classes: [
+///         (left: "TVWY", right: "aceo", amount: -1),
+///     ],
+/// )
+/// "#;
+/// ron::from_str::<ImageFontKerning>(s).unwrap();
+/// ```
+///
+/// Both fields are optional. Where a pair is covered by several entries,
+/// `pairs` take precedence over `classes`, and later classes take precedence
+/// over earlier ones.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ImageFontKerning {
+    /// Kerning for individual pairs of characters. Each key must consist of
+    /// exactly two characters, the first being the left and the second being
+    /// the right character of the pair.
+    #[serde(default)]
+    pub pairs: HashMap<String, f32>,
+
+    /// Kerning for classes of characters. See [`ImageFontKerningClass`].
+    #[serde(default)]
+    pub classes: Vec<ImageFontKerningClass>,
+}
+
+/// Kerning applied to every pair formed by a character from `left` followed by
+/// a character from `right`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ImageFontKerningClass {
+    /// The characters that may come first in a pair.
+    pub left: String,
+    /// The characters that may come second in a pair.
+    pub right: String,
+    /// The adjustment to the distance between the two characters of a pair.
+    pub amount: f32,
+}
+
+impl ImageFontKerning {
+    /// Validates that every key in `pairs` consists of exactly two
+    /// characters.
+    ///
+    /// # Errors
+    /// Returns [`ImageFontDescriptorValidationError::InvalidKerningPair`] for
+    /// the first key found that doesn't.
+    fn validate(&self) -> Result<(), ImageFontDescriptorValidationError> {
+        if let Some(pair) = self
+            .pairs
+            .keys()
+            .find(|pair| Self::split_pair(pair).is_none())
+        {
+            return Err(ImageFontDescriptorValidationError::InvalidKerningPair {
+                pair: pair.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Expands the classes and pairs into a single map from character pairs to
+    /// their kerning, as used by [`ImageFont::kerning_pairs`].
+    ///
+    /// # Returns
+    /// The kerning of every pair covered by this `ImageFontKerning`. Keys in
+    /// `pairs` that aren't exactly two characters long are skipped.
+    fn into_kerning_pairs(self) -> HashMap<(char, char), f32> {
+        let mut kerning_pairs = HashMap::new();
+
+        for class in &self.classes {
+            for left in class.left.chars() {
+                for right in class.right.chars() {
+                    kerning_pairs.insert((left, right), class.amount);
+                }
+            }
+        }
+
+        for (pair, amount) in &self.pairs {
+            if let Some(pair) = Self::split_pair(pair) {
+                kerning_pairs.insert(pair, *amount);
+            }
+        }
+
+        kerning_pairs
+    }
+
+    /// Splits a key of `pairs` into its two characters.
+    ///
+    /// # Returns
+    /// - `Some((left, right))`: If `pair` consists of exactly two characters.
+    /// - `None`: Otherwise.
+    fn split_pair(pair: &str) -> Option<(char, char)> {
+        let mut characters = pair.chars();
+        match (characters.next(), characters.next(), characters.next()) {
+            (Some(left), Some(right), None) => Some((left, right)),
+            _ => None,
+        }
+    }
 }
 
 /// Errors that can show up during validation.
@@ -258,6 +1065,13 @@ pub enum ImageFontDescriptorValidationError {
     /// string.
     #[error("Automatic layout string is empty.")]
     EmptyLayoutString,
+
+    /// A key in the kerning pairs doesn't consist of exactly two characters.
+    #[error("Kerning pair \"{pair}\" must consist of exactly two characters.")]
+    InvalidKerningPair {
+        /// The offending key.
+        pair: String,
+    },
 }
 
 impl ImageFontDescriptor {
@@ -283,27 +1097,154 @@ impl ImageFontDescriptor {
         image: Utf8PathBuf,
         layout: ImageFontLayout,
     ) -> Result<Self, ImageFontDescriptorValidationError> {
-        let value = Self { image, layout };
+        let value = Self {
+            image,
+            layout,
+            additional_pages: Vec::new(),
+            trim_glyphs: None,
+            glyph_metrics: HashMap::new(),
+            baseline: None,
+            line_height: None,
+            kerning: ImageFontKerning::default(),
+            fallback_character: None,
+        };
         value.validate()?;
         Ok(value)
     }
 
+    /// Replaces the additional pages of the descriptor, validating the new
+    /// pages.
+    ///
+    /// # Parameters
+    /// - `additional_pages`: The images of the font other than its main image,
+    ///   each with its own layout. See [`ImageFontPage`].
+    ///
+    /// # Returns
+    /// The descriptor with the new additional pages if validation succeeds.
+    ///
+    /// # Errors
+    /// Returns an [`ImageFontDescriptorValidationError`] if any of the pages
+    /// does not pass validation.
+    pub fn with_additional_pages(
+        self,
+        additional_pages: Vec<ImageFontPage>,
+    ) -> Result<Self, ImageFontDescriptorValidationError> {
+        for page in &additional_pages {
+            page.validate()?;
+        }
+        Ok(Self {
+            additional_pages,
+            ..self
+        })
+    }
+
+    /// Replaces the kerning information of the descriptor, validating the new
+    /// kerning information.
+    ///
+    /// # Parameters
+    /// - `kerning`: The kerning to use for the font. See [`ImageFontKerning`].
+    ///
+    /// # Returns
+    /// The descriptor with the new kerning information if validation succeeds.
+    ///
+    /// # Errors
+    /// Returns an [`ImageFontDescriptorValidationError`] if the kerning
+    /// information does not pass validation.
+    pub fn with_kerning(
+        self,
+        kerning: ImageFontKerning,
+    ) -> Result<Self, ImageFontDescriptorValidationError> {
+        kerning.validate()?;
+        Ok(Self { kerning, ..self })
+    }
+
+    /// Replaces the glyph metrics and baseline of the descriptor.
+    ///
+    /// # Parameters
+    /// - `glyph_metrics`: Placement information for individual glyphs. See
+    ///   [`GlyphMetrics`].
+    /// - `baseline`: The distance from the top of a line to the baseline, or
+    ///   `None` to use the height of the tallest glyph without a `baseline`.
+    ///
+    /// # Returns
+    /// The descriptor with the new glyph metrics and baseline.
+    #[must_use]
+    pub fn with_glyph_metrics(
+        self,
+        glyph_metrics: HashMap<char, GlyphMetrics>,
+        baseline: Option<u32>,
+    ) -> Self {
+        Self {
+            glyph_metrics,
+            baseline,
+            ..self
+        }
+    }
+
+    /// Replaces the line height of the descriptor.
+    ///
+    /// # Parameters
+    /// - `line_height`: The height of a line of text, or `None` to use the
+    ///   height of the tallest glyph of each text.
+    ///
+    /// # Returns
+    /// The descriptor with the new line height.
+    #[must_use]
+    pub fn with_line_height(self, line_height: Option<u32>) -> Self {
+        Self {
+            line_height,
+            ..self
+        }
+    }
+
+    /// Replaces the glyph trimming settings of the descriptor.
+    ///
+    /// # Parameters
+    /// - `trim_glyphs`: How to shrink the glyphs to their visible pixels, or
+    ///   `None` to keep the rectangles given by the layouts.
+    ///
+    /// # Returns
+    /// The descriptor with the new glyph trimming settings.
+    #[must_use]
+    pub fn with_trim_glyphs(self, trim_glyphs: Option<GlyphTrimming>) -> Self {
+        Self {
+            trim_glyphs,
+            ..self
+        }
+    }
+
+    /// Replaces the fallback character of the descriptor.
+    ///
+    /// # Parameters
+    /// - `fallback_character`: The character to render in place of characters
+    ///   the font doesn't contain, or `None` to leave such characters out.
+    ///
+    /// # Returns
+    /// The descriptor with the new fallback character.
+    #[must_use]
+    pub fn with_fallback_character(self, fallback_character: Option<char>) -> Self {
+        Self {
+            fallback_character,
+            ..self
+        }
+    }
+
     /// Validates the `ImageFontDescriptor` struct to ensure all required fields
     /// are populated.
     ///
     /// # Errors
-    ///   - `ImageFontLoadError::EmptyImagePath` if the `image` path is empty.
+    ///   - `ImageFontLoadError::EmptyImagePath` if the `image` path, or that of
+    ///     an additional page, is empty.
     ///   - `ImageFontLoadError::EmptyLayoutString` if the `layout` string for
-    ///     `Automatic` is empty.
+    ///     `Automatic` is empty, for the main image or an additional page.
+    ///   - `ImageFontLoadError::InvalidKerningPair` if a kerning pair isn't
+    ///     made up of exactly two characters.
     fn validate(&self) -> Result<(), ImageFontDescriptorValidationError> {
-        if self.image.as_str().trim().is_empty() {
-            return Err(ImageFontDescriptorValidationError::EmptyImagePath);
-        }
-        if matches!(self.layout, ImageFontLayout::Automatic(ref layout) if layout.trim().is_empty())
-        {
-            return Err(ImageFontDescriptorValidationError::EmptyLayoutString);
+        validate_page(&self.image, &self.layout)?;
+        for page in &self.additional_pages {
+            page.validate()?;
         }
-        Ok(())
+        self.kerning.validate()
     }
 
     /// Gets the path to the image file containing the font glyphs.
@@ -330,6 +1271,93 @@ impl ImageFontDescriptor {
     pub fn layout(&self) -> &ImageFontLayout {
         &self.layout
     }
+
+    /// Gets the additional pages of the font.
+    ///
+    /// This is the value of the `additional_pages` field, which is empty if
+    /// the font consists of a single image.
+    ///
+    /// # Returns
+    /// A slice of the `ImageFontPage`s following the font's main image.
+    #[must_use]
+    pub fn additional_pages(&self) -> &[ImageFontPage] {
+        &self.additional_pages
+    }
+
+    /// Gets the kerning information of the font.
+    ///
+    /// This is the value of the `kerning` field, which is empty if the
+    /// descriptor doesn't specify any kerning.
+    ///
+    /// # Returns
+    /// A reference to the `ImageFontKerning` of the font.
+    #[must_use]
+    pub fn kerning(&self) -> &ImageFontKerning {
+        &self.kerning
+    }
+
+    /// Gets the placement information for individual glyphs of the font.
+    ///
+    /// This is the value of the `glyph_metrics` field, which is empty if the
+    /// descriptor doesn't specify any metrics.
+    ///
+    /// # Returns
+    /// A reference to the map from characters to their [`GlyphMetrics`].
+    #[must_use]
+    pub fn glyph_metrics(&self) -> &HashMap<char, GlyphMetrics> {
+        &self.glyph_metrics
+    }
+
+    /// Gets the baseline of the font.
+    ///
+    /// This is the value of the `baseline` field, which is `None` if the
+    /// descriptor doesn't specify one.
+    ///
+    /// # Returns
+    /// The distance from the top of a line to the baseline, in pixels, if
+    /// given.
+    #[must_use]
+    pub fn baseline(&self) -> Option<u32> {
+        self.baseline
+    }
+
+    /// Gets the line height of the font.
+    ///
+    /// This is the value of the `line_height` field, which is `None` if the
+    /// descriptor doesn't specify one.
+    ///
+    /// # Returns
+    /// The height of a line of text, in pixels, if given.
+    #[must_use]
+    pub fn line_height(&self) -> Option<u32> {
+        self.line_height
+    }
+
+    /// Gets the glyph trimming settings of the font.
+    ///
+    /// This is the value of the `trim_glyphs` field, which is `None` if the
+    /// descriptor doesn't specify it.
+    ///
+    /// # Returns
+    /// The settings for shrinking the glyphs to their visible pixels, or
+    /// `None` if glyphs keep the rectangles given by the layouts.
+    #[must_use]
+    pub fn trim_glyphs(&self) -> Option<GlyphTrimming> {
+        self.trim_glyphs
+    }
+
+    /// Gets the fallback character of the font.
+    ///
+    /// This is the value of the `fallback_character` field, which is `None` if
+    /// the descriptor doesn't specify one.
+    ///
+    /// # Returns
+    /// The character rendered in place of characters the font doesn't
+    /// contain, if any.
+    #[must_use]
+    pub fn fallback_character(&self) -> Option<char> {
+        self.fallback_character
+    }
 }
 
 /// Loader for [`ImageFont`]s.
@@ -380,6 +1408,18 @@ pub enum ImageFontLoadError {
     /// The asset path has no parent directory.
     #[error("Asset path has no parent directory")]
     MissingParentPath,
+
+    /// The configured fallback character isn't one of the characters in the
+    /// font, so it can't be rendered in place of other characters.
+    #[error("Fallback character '{0}' is not part of the font")]
+    MissingFallbackCharacter(char),
+
+    /// The pixels of one of the font's images couldn't be read to trim its
+    /// glyphs. This may occur if the image uses a texture format that doesn't
+    /// support reading individual pixels, or a glyph's rectangle lies outside
+    /// of the image.
+    #[error("Couldn't read the font's pixels to trim its glyphs: {0}")]
+    GlyphTrimming(TextureAccessError),
 }
 
 impl From<LoadDirectError> for ImageFontLoadError {
@@ -400,12 +1440,22 @@ pub struct ImageFontLoaderSettings {
     /// preserving a crisp, pixelated appearance. This is usually ideal for
     /// pixel-art fonts.
     pub image_sampler: ImageSampler,
+
+    /// The character to render in place of characters the font doesn't
+    /// contain. Overrides the fallback character given by the font file
+    /// itself, i.e. the `fallback_character` of an `.image_font.ron`
+    /// descriptor or the invalid glyph (id `-1`) of a `.fnt` font.
+    ///
+    /// The default is `None`, which uses the font file's fallback character,
+    /// if any.
+    pub fallback_character: Option<char>,
 }
 
 impl Default for ImageFontLoaderSettings {
     fn default() -> Self {
         Self {
             image_sampler: ImageSampler::Descriptor(ImageSamplerDescriptor::nearest()),
+            fallback_character: None,
         }
     }
 }
@@ -428,42 +1478,59 @@ impl AssetLoader for ImageFontLoader {
     ) -> Result<Self::Asset, Self::Error> {
         let font_descriptor = read_and_validate_font_descriptor(reader).await?;
 
-        // need the image loaded immediately because we need its size
-        let image_path = load_context
-            .path()
-            .parent()
-            .ok_or(ImageFontLoadError::MissingParentPath)?
-            .join(font_descriptor.image());
-        let Some(mut image) = load_context
-            .loader()
-            .immediate()
-            .with_unknown_type()
-            .load(image_path.as_path())
-            .await?
-            .take::<Image>()
-        else {
-            let path = match Utf8PathBuf::try_from(image_path) {
-                Ok(path) => path,
-                Err(error) => return Err(ImageFontLoadError::InvalidPath(error.from_path_error())),
-            };
-            return Err(ImageFontLoadError::NotAnImage(path));
-        };
-
-        image.sampler = settings.image_sampler.clone();
-        let size = image.size();
+        // need the images loaded immediately because we need their sizes
+        let mut images = Vec::with_capacity(1 + font_descriptor.additional_pages.len());
+        for image_path in iter::once(font_descriptor.image()).chain(
+            font_descriptor
+                .additional_pages
+                .iter()
+                .map(ImageFontPage::image),
+        ) {
+            images.push(load_image(image_path, settings, load_context).await?);
+        }
 
-        let (atlas_character_map, layout) =
-            descriptor_to_character_map_and_layout(font_descriptor, size)?;
+        let fallback_character = settings
+            .fallback_character
+            .or(font_descriptor.fallback_character);
+        let trim_glyphs = font_descriptor.trim_glyphs;
+        let (atlas_character_map, mut layouts, kerning_pairs, line_metrics) =
+            descriptor_to_character_map_and_layouts(font_descriptor, &images)?;
+        if let Some(trim_glyphs) = trim_glyphs {
+            for (layout, image) in layouts.iter_mut().zip(&images) {
+                trim_glyphs
+                    .trim_atlas_layout(layout, image)
+                    .map_err(ImageFontLoadError::GlyphTrimming)?;
+            }
+        }
+        validate_fallback_character(fallback_character, &atlas_character_map)?;
 
-        let image_handle = load_context.add_labeled_asset(String::from("texture"), image);
-        let layout_handle = load_context.add_labeled_asset(String::from("layout"), layout);
+        // The first page keeps the labels of single-image fonts
+        let (image_handles, layout_handles) = images
+            .into_iter()
+            .zip(layouts)
+            .enumerate()
+            .map(|(page, (image, layout))| {
+                let (texture_label, layout_label) = if page == 0 {
+                    (String::from("texture"), String::from("layout"))
+                } else {
+                    (format!("texture_{page}"), format!("layout_{page}"))
+                };
+                (
+                    load_context.add_labeled_asset(texture_label, image),
+                    load_context.add_labeled_asset(layout_label, layout),
+                )
+            })
+            .unzip();
 
-        let image_font = ImageFont::new(
-            vec![image_handle],
+        let mut image_font = ImageFont::new(
+            image_handles,
             atlas_character_map,
-            vec![layout_handle],
+            layout_handles,
             settings.image_sampler.clone(),
         );
+        image_font.kerning_pairs = kerning_pairs;
+        image_font.fallback_character = fallback_character;
+        image_font.line_metrics = line_metrics;
         Ok(image_font)
     }
 
@@ -472,6 +1539,48 @@ impl AssetLoader for ImageFontLoader {
     }
 }
 
+/// Loads one of the images of an image font.
+///
+/// # Parameters
+/// - `image`: The path to the image, relative to the font's RON file.
+/// - `settings`: The loader settings, providing the image sampler to use.
+/// - `load_context`: The load context of the font.
+///
+/// # Returns
+/// The loaded image, with its sampler set to the one given by the settings.
+///
+/// # Errors
+/// Returns an error if the image can't be loaded, or the path doesn't point to
+/// an image.
+async fn load_image(
+    image: &Utf8Path,
+    settings: &ImageFontLoaderSettings,
+    load_context: &mut LoadContext<'_>,
+) -> Result<Image, ImageFontLoadError> {
+    let image_path = load_context
+        .path()
+        .parent()
+        .ok_or(ImageFontLoadError::MissingParentPath)?
+        .join(image);
+    let Some(mut image) = load_context
+        .loader()
+        .immediate()
+        .with_unknown_type()
+        .load(image_path.as_path())
+        .await?
+        .take::<Image>()
+    else {
+        let path = match Utf8PathBuf::try_from(image_path) {
+            Ok(path) => path,
+            Err(error) => return Err(ImageFontLoadError::InvalidPath(error.from_path_error())),
+        };
+        return Err(ImageFontLoadError::NotAnImage(path));
+    };
+
+    image.sampler = settings.image_sampler.clone();
+    Ok(image)
+}
+
 /// Reads and validates an `ImageFontDescriptor` from a reader.
 ///
 /// This function reads the entirety of the data provided by the `reader`,
@@ -505,39 +1614,188 @@ async fn read_and_validate_font_descriptor(
     Ok(font_descriptor)
 }
 
-/// Converts an `ImageFontDescriptor` into a character map and texture atlas
-/// layout.
+/// Converts an `ImageFontDescriptor` into a character map, texture atlas
+/// layouts and kerning map.
 ///
 /// This function processes the given `ImageFontDescriptor` to generate a
-/// character-to-index map and a [`TextureAtlasLayout`], based on the provided
-/// image size. It uses the descriptor's layout information to map characters to
-/// specific regions within the texture atlas.
+/// character-to-index map and one [`TextureAtlasLayout`] per page, based on
+/// the provided images. It uses the descriptor's layout information to
+/// map characters to specific regions within the texture atlases.
 ///
 /// # Parameters
-/// - `font_descriptor`: The `ImageFontDescriptor` containing the layout.
-/// - `image_size`: A [`UVec2`] representing the dimensions of the image
-///   containing the font glyphs.
+/// - `font_descriptor`: The `ImageFontDescriptor` containing the layouts.
+/// - `images`: The images containing the font glyphs: the main image first,
+///   followed by those of the additional pages.
 ///
 /// # Returns
 /// A tuple where
-/// - the first element is a `HashMap<char, usize>` mapping characters to
-///   indices in the texture atlas.
-/// - the second element is a [`TextureAtlasLayout`] describing the texture
-///   atlas layout.
+/// - the first element is a `HashMap<char, ImageFontCharacter>` mapping
+///   characters to their page and index in that page's texture atlas.
+/// - the second element contains a [`TextureAtlasLayout`] per page, describing
+///   its texture atlas layout.
+/// - the third element maps pairs of characters to their kerning, as described
+///   by the descriptor's [`ImageFontKerning`].
+/// - the fourth element contains the font's line metrics, if the descriptor has
+///   a `line_height`.
+///
+/// The characters' offsets and advances are set according to the descriptor's
+/// glyph metrics.
 ///
 /// # Errors
 /// This function will return an [`ImageFontLoadError`] in the following cases:
-/// - If there are any validation errors in the layout. See
-///   [`ImageFontLayoutValidationError`] for details.
-fn descriptor_to_character_map_and_layout(
+/// - If there are any validation errors in the layouts, including a character
+///   appearing on several pages or glyph metrics for a character that isn't in
+///   the font. See [`ImageFontLayoutValidationError`] for details.
+///
+/// # Panics
+/// If `images` doesn't hold an image for every page of the descriptor.
+fn descriptor_to_character_map_and_layouts(
     font_descriptor: ImageFontDescriptor,
-    image_size: UVec2,
-) -> Result<(HashMap<char, ImageFontCharacter>, TextureAtlasLayout), ImageFontLoadError> {
-    let rect_character_map = font_descriptor.layout.into_character_rect_map(image_size)?;
-    let (atlas_character_map, layout) =
-        ImageFont::mapped_atlas_layout_from_char_map(0, image_size, rect_character_map.into_iter());
-    Ok((atlas_character_map, layout))
+    images: &[Image],
+) -> Result<CharacterMapLayoutsKerningAndMetrics, ImageFontLoadError> {
+    let layouts = iter::once(font_descriptor.layout).chain(
+        font_descriptor
+            .additional_pages
+            .into_iter()
+            .map(|page| page.layout),
+    );
+
+    let mut atlas_character_map = HashMap::new();
+    let mut atlas_layouts = Vec::with_capacity(images.len());
+    for (page, layout) in layouts.enumerate() {
+        let image = &images[page];
+        let rect_character_map = layout.into_character_rect_map(image)?;
+        let (page_character_map, atlas_layout) = ImageFont::mapped_atlas_layout_from_char_map(
+            page,
+            image.size(),
+            rect_character_map.into_iter(),
+        );
+
+        for (character, image_font_character) in page_character_map {
+            if atlas_character_map
+                .insert(character, image_font_character)
+                .is_some()
+            {
+                return Err(ImageFontLayoutValidationError::CharacterOnSeveralPages {
+                    page,
+                    character,
+                }
+                .into());
+            }
+        }
+        atlas_layouts.push(atlas_layout);
+    }
+
+    let baseline = apply_glyph_metrics(
+        &mut atlas_character_map,
+        &atlas_layouts,
+        &font_descriptor.glyph_metrics,
+        font_descriptor.baseline,
+    )?;
+    let line_metrics = font_descriptor
+        .line_height
+        .map(|line_height| ImageFontLineMetrics::new(line_height, baseline));
+
+    let kerning_pairs = font_descriptor.kerning.into_kerning_pairs();
+    Ok((
+        atlas_character_map,
+        atlas_layouts,
+        kerning_pairs,
+        line_metrics,
+    ))
 }
 
+/// Sets the offsets and advances of a font's characters according to their
+/// glyph metrics.
+///
+/// # Parameters
+/// - `atlas_character_map`: The font's characters.
+/// - `atlas_layouts`: The texture atlas layouts of the font's pages, giving the
+///   sizes of the glyphs.
+/// - `glyph_metrics`: The metrics of the glyphs that have any.
+/// - `baseline`: The distance from the top of a line to the baseline, or `None`
+///   to use the height of the tallest glyph without a `baseline`.
+///
+/// # Returns
+/// The distance from the top of a line to the baseline that the glyphs were
+/// aligned to.
+///
+/// # Errors
+/// Returns [`ImageFontLayoutValidationError::MetricsForMissingCharacter`] if
+/// `glyph_metrics` contains a character that isn't in `atlas_character_map`.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "glyph sizes are small enough to be represented exactly"
+)]
+fn apply_glyph_metrics(
+    atlas_character_map: &mut HashMap<char, ImageFontCharacter>,
+    atlas_layouts: &[TextureAtlasLayout],
+    glyph_metrics: &HashMap<char, GlyphMetrics>,
+    baseline: Option<u32>,
+) -> Result<u32, ImageFontLayoutValidationError> {
+    let glyph_height = |character: &ImageFontCharacter| {
+        atlas_layouts[character.page_index].textures[character.character_index].height()
+    };
+    let baseline = baseline.unwrap_or_else(|| {
+        atlas_character_map
+            .iter()
+            .filter(|&(character, _)| {
+                glyph_metrics
+                    .get(character)
+                    .is_none_or(|metrics| metrics.baseline.is_none())
+            })
+            .map(|(_, image_font_character)| glyph_height(image_font_character))
+            .max()
+            .unwrap_or_default()
+    });
+
+    for (&character, metrics) in glyph_metrics {
+        let Some(image_font_character) = atlas_character_map.get_mut(&character) else {
+            return Err(ImageFontLayoutValidationError::MetricsForMissingCharacter { character });
+        };
+
+        let baseline_offset = metrics.baseline.map_or(0.0, |glyph_baseline| {
+            baseline as f32 - glyph_baseline as f32
+        });
+        // `ImageFontCharacter::offsets` points up, like Bevy's coordinates do
+        image_font_character.offsets =
+            Vec2::new(metrics.offset.x, -(metrics.offset.y + baseline_offset));
+        image_font_character.x_advance = metrics.x_advance;
+    }
+
+    Ok(baseline)
+}
+
+/// Ensures that a font's fallback character, if it has one, is one of its
+/// characters.
+///
+/// # Parameters
+/// - `fallback_character`: The fallback character of the font.
+/// - `atlas_character_map`: The characters of the font.
+///
+/// # Errors
+/// Returns [`ImageFontLoadError::MissingFallbackCharacter`] if
+/// `fallback_character` is `Some` but not a key of `atlas_character_map`.
+fn validate_fallback_character<V>(
+    fallback_character: Option<char>,
+    atlas_character_map: &HashMap<char, V>,
+) -> Result<(), ImageFontLoadError> {
+    match fallback_character {
+        Some(character) if !atlas_character_map.contains_key(&character) => {
+            Err(ImageFontLoadError::MissingFallbackCharacter(character))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The character map, texture atlas layouts, kerning map and line metrics
+/// created by [`descriptor_to_character_map_and_layouts`].
+type CharacterMapLayoutsKerningAndMetrics = (
+    HashMap<char, ImageFontCharacter>,
+    Vec<TextureAtlasLayout>,
+    HashMap<(char, char), f32>,
+    Option<ImageFontLineMetrics>,
+);
+
 #[cfg(test)]
 mod tests;
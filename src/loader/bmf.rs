@@ -16,18 +16,32 @@ use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext},
     image::Image,
     math::{URect, UVec2},
+    render::render_resource::{TextureDimension, TextureFormat},
 };
 use bevy_image::TextureAtlasLayout;
+use bmfont_rs::Chnl;
 use camino::Utf8Path;
 use strum::{AsRefStr, EnumIter, IntoEnumIterator as _, VariantNames};
 use thiserror::Error;
 use tracing::warn;
 
-use crate::ImageFontCharacter;
+use crate::loader::bmf::character_encoding::CharacterEncoding;
 use crate::{
-    loader::{ImageFontLoadError, ImageFontLoaderSettings},
+    loader::{validate_fallback_character, ImageFontLoadError, ImageFontLoaderSettings},
     ImageFont,
 };
+use crate::{ImageFontCharacter, ImageFontLineMetrics};
+
+mod character_encoding;
+
+/// The character id `BMFont` uses for the invalid glyph, which is shown in
+/// place of characters the font doesn't contain. It is written as `-1` in font
+/// files.
+const INVALID_GLYPH_ID: u32 = u32::MAX;
+
+/// The character the invalid glyph of a font is stored under, unless the font
+/// defines a glyph for this character itself.
+const INVALID_GLYPH_CHARACTER: char = char::REPLACEMENT_CHARACTER;
 
 /// Loader for [`ImageFont`]s.
 #[derive(Debug, Default)]
@@ -51,14 +65,16 @@ pub enum BmFontLoadError {
     #[error(transparent)]
     BmFontError(#[from] bmfont_rs::Error),
 
-    /// [`BmFontLoader`] only supports Unicode fonts, but the loaded font is
-    /// not.
-    #[error("BmFontLoader only supports unicode fonts")]
+    /// [`BmFontLoader`] only supports Unicode fonts and fonts using the `ANSI`
+    /// (Windows-1252), `DEFAULT` or `OEM` (code page 437) character sets, but
+    /// the loaded font uses a different character set.
+    #[error("BmFontLoader only supports unicode, ANSI and OEM fonts")]
     CharsetUnsupported,
 
-    /// BMF fonts must not be packed, but the loaded font is packed.
-    #[error("BmFontLoader only supports non-packed fonts")]
-    PackedUnsupported,
+    /// The font is packed, but one of its page images has a texture format
+    /// the glyph channels can't be extracted from.
+    #[error("Can't unpack glyph channels from a page image with texture format {0:?}")]
+    UnsupportedPackedTextureFormat(TextureFormat),
 }
 
 impl AssetLoader for BmFontLoader {
@@ -75,9 +91,17 @@ impl AssetLoader for BmFontLoader {
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let data = read_bmf_data(reader).await?;
-        let bm_font = parse_bmf_data(&data, load_context)?;
-
-        let mut atlas_character_map = process_bmf_characters(&bm_font);
+        let (bm_font, encoding) = parse_bmf_data(&data, load_context)?;
+
+        let (mut atlas_character_map, invalid_glyph_character) =
+            process_bmf_characters(&bm_font, encoding);
+        let fallback_character = settings.fallback_character.or(invalid_glyph_character);
+        validate_fallback_character(fallback_character, &atlas_character_map)?;
+        let kerning_pairs = process_bmf_kernings(&bm_font, encoding);
+        let line_metrics = ImageFontLineMetrics::new(
+            u32::from(bm_font.common.line_height),
+            u32::from(bm_font.common.base),
+        );
         let (image_handles, atlas_layout_handles) =
             load_images_and_textures(&bm_font, &mut atlas_character_map, settings, load_context)
                 .await?;
@@ -86,6 +110,9 @@ impl AssetLoader for BmFontLoader {
             image_handles,
             atlas_character_map,
             atlas_layout_handles,
+            kerning_pairs,
+            fallback_character,
+            line_metrics,
             settings,
         ))
     }
@@ -196,10 +223,13 @@ async fn read_bmf_data(reader: &mut dyn Reader) -> Result<Vec<u8>, ImageFontLoad
 }
 
 /// Parses the BMF font data and validates it.
+///
+/// # Returns
+/// The parsed font, along with the encoding of its character ids.
 fn parse_bmf_data(
     data: &[u8],
     load_context: &mut LoadContext<'_>,
-) -> Result<bmfont_rs::Font, BmFontLoadError> {
+) -> Result<(bmfont_rs::Font, CharacterEncoding), BmFontLoadError> {
     let path = load_context.path();
     let path = match <&Utf8Path>::try_from(path) {
         Ok(path) => path,
@@ -215,21 +245,31 @@ fn parse_bmf_data(
 
     let bm_font = from_bytes(data)?;
 
-    if !bm_font.info.unicode {
+    let Some(encoding) = CharacterEncoding::from_info(&bm_font.info) else {
         return Err(BmFontLoadError::CharsetUnsupported);
-    }
-    if bm_font.common.packed {
-        return Err(BmFontLoadError::PackedUnsupported);
-    }
+    };
 
-    Ok(bm_font)
+    Ok((bm_font, encoding))
 }
 
-/// Processes BMF characters into an atlas character map.
+/// Processes BMF characters into an atlas character map, decoding their ids
+/// using the given `encoding`.
+///
+/// The invalid glyph, if the font has one, is stored under
+/// [`INVALID_GLYPH_CHARACTER`] unless the font defines that character itself.
+///
+/// # Returns
+/// The atlas character map, along with the character the invalid glyph is
+/// stored under, if any.
 fn process_bmf_characters(
     bm_font: &bmfont_rs::Font,
-) -> HashMap<char, (&bmfont_rs::Char, URect, Option<usize>)> {
+    encoding: CharacterEncoding,
+) -> (
+    HashMap<char, (&bmfont_rs::Char, URect, Option<(usize, usize)>)>,
+    Option<char>,
+) {
     let mut atlas_character_map = HashMap::new();
+    let mut invalid_glyph = None;
     for char in &bm_font.chars {
         let x = u32::from(char.x);
         let y = u32::from(char.y);
@@ -242,7 +282,9 @@ fn process_bmf_characters(
             },
         };
 
-        if let Some(character) = char::from_u32(char.id) {
+        if char.id == INVALID_GLYPH_ID {
+            invalid_glyph = Some((char, rect, None));
+        } else if let Some(character) = encoding.decode(char.id) {
             atlas_character_map.insert(character, (char, rect, None));
         } else {
             warn!(
@@ -252,13 +294,166 @@ fn process_bmf_characters(
         }
     }
 
-    atlas_character_map
+    let invalid_glyph_character = invalid_glyph.map(|invalid_glyph| {
+        atlas_character_map
+            .entry(INVALID_GLYPH_CHARACTER)
+            .or_insert(invalid_glyph);
+        INVALID_GLYPH_CHARACTER
+    });
+
+    (atlas_character_map, invalid_glyph_character)
+}
+
+/// Processes BMF kerning information into a map of character pairs to the
+/// adjustment of the distance between them, decoding character ids using the
+/// given `encoding`.
+fn process_bmf_kernings(
+    bm_font: &bmfont_rs::Font,
+    encoding: CharacterEncoding,
+) -> HashMap<(char, char), f32> {
+    let mut kerning_pairs = HashMap::new();
+    for kerning in &bm_font.kernings {
+        if let (Some(first), Some(second)) = (
+            encoding.decode(kerning.first),
+            encoding.decode(kerning.second),
+        ) {
+            kerning_pairs.insert((first, second), f32::from(kerning.amount));
+        } else {
+            warn!("Skipping kerning pair with invalid character id. Full kerning definition: {kerning:?}");
+        }
+    }
+
+    kerning_pairs
+}
+
+/// A color channel of a packed BMF page image that holds glyphs.
+///
+/// Packed fonts store up to four glyphs on top of each other, one in each
+/// channel of the page image, to save texture memory. Which channel a glyph
+/// lives in is given by its `chnl` value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum PackedChannel {
+    /// The red channel.
+    Red,
+    /// The green channel.
+    Green,
+    /// The blue channel.
+    Blue,
+    /// The alpha channel.
+    Alpha,
+}
+
+impl PackedChannel {
+    /// Determines which single channel a glyph is stored in.
+    ///
+    /// # Parameters
+    /// - `chnl`: The `chnl` value of the glyph.
+    ///
+    /// # Returns
+    /// - `Some(PackedChannel)`: If the glyph is stored in exactly one channel.
+    /// - `None`: If the glyph is stored in several (usually all) channels, in
+    ///   which case the page image can be used for it as it is.
+    fn from_chnl(chnl: Chnl) -> Option<Self> {
+        match u8::from(chnl) {
+            1 => Some(Self::Blue),
+            2 => Some(Self::Green),
+            4 => Some(Self::Red),
+            8 => Some(Self::Alpha),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the channel's byte within an RGBA pixel.
+    fn byte_offset(self) -> usize {
+        match self {
+            Self::Red => 0,
+            Self::Green => 1,
+            Self::Blue => 2,
+            Self::Alpha => 3,
+        }
+    }
+}
+
+/// Determines the channel of a page image a character's glyph is stored in.
+///
+/// # Parameters
+/// - `bm_font`: The font the character belongs to.
+/// - `char`: The character.
+///
+/// # Returns
+/// - `Some(PackedChannel)`: If the font is packed and the glyph is stored in a
+///   single channel.
+/// - `None`: If the glyph is stored in the page image as a whole.
+fn packed_channel(bm_font: &bmfont_rs::Font, char: &bmfont_rs::Char) -> Option<PackedChannel> {
+    if bm_font.common.packed {
+        PackedChannel::from_chnl(char.chnl)
+    } else {
+        None
+    }
+}
+
+/// Extracts the glyphs stored in one channel of a packed page image into an
+/// image of their own.
+///
+/// The value of the channel is used as the glyph's coverage, so the resulting
+/// image is white, with the alpha channel taken from the selected channel of
+/// the page image. Like the page, it has four bytes per pixel, so every
+/// channel that is unpacked takes up as much memory as the page itself.
+///
+/// # Parameters
+/// - `image`: The packed page image.
+/// - `channel`: The channel to extract.
+///
+/// # Returns
+/// An image the size of `image`, containing only the glyphs of `channel`.
+///
+/// # Errors
+/// Returns [`BmFontLoadError::UnsupportedPackedTextureFormat`] if `image`
+/// can't be converted to an 8-bit RGBA image.
+fn unpack_channel(image: &Image, channel: PackedChannel) -> Result<Image, BmFontLoadError> {
+    let format = image.texture_descriptor.format;
+    let converted;
+    let image = if matches!(
+        format,
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb
+    ) {
+        image
+    } else {
+        converted = image
+            .convert(TextureFormat::Rgba8UnormSrgb)
+            .ok_or(BmFontLoadError::UnsupportedPackedTextureFormat(format))?;
+        &converted
+    };
+    let data = image
+        .data
+        .as_ref()
+        .ok_or(BmFontLoadError::UnsupportedPackedTextureFormat(format))?;
+
+    let byte_offset = channel.byte_offset();
+    let unpacked = data
+        .chunks_exact(4)
+        .flat_map(|pixel| [u8::MAX, u8::MAX, u8::MAX, pixel[byte_offset]])
+        .collect();
+
+    let mut unpacked_image = Image::new(
+        image.texture_descriptor.size,
+        TextureDimension::D2,
+        unpacked,
+        TextureFormat::Rgba8UnormSrgb,
+        image.asset_usage,
+    );
+    unpacked_image.sampler = image.sampler.clone();
+    Ok(unpacked_image)
 }
 
 /// Loads font images and creates texture atlases.
+///
+/// Every page of the font becomes one texture of the [`ImageFont`]. For
+/// packed fonts, every channel of a page that holds glyphs is extracted into
+/// a texture of its own instead, see [`unpack_channel`].
 async fn load_images_and_textures(
     bm_font: &bmfont_rs::Font,
-    atlas_character_map: &mut HashMap<char, (&bmfont_rs::Char, URect, Option<usize>)>,
+    atlas_character_map: &mut HashMap<char, (&bmfont_rs::Char, URect, Option<(usize, usize)>)>,
     settings: &ImageFontLoaderSettings,
     load_context: &mut LoadContext<'_>,
 ) -> Result<
@@ -292,20 +487,50 @@ async fn load_images_and_textures(
         image.sampler = settings.image_sampler.clone();
 
         let size = image.size();
-        let image_handle = load_context.add_labeled_asset(format!("texture_{page_no}"), image);
-        image_handles.push(image_handle);
-
-        let mut atlas_layout = TextureAtlasLayout::new_empty(size);
-        for (_, &mut (_, rect, ref mut texture_handle)) in atlas_character_map
-            .iter_mut()
-            .filter(|&(_, &mut (char, _, _))| char.page as usize == page_no)
-        {
-            texture_handle.replace(atlas_layout.add_texture(rect));
-        }
+        let textures = if bm_font.common.packed {
+            let mut channels: Vec<_> = atlas_character_map
+                .values()
+                .filter(|&&(char, _, _)| char.page as usize == page_no)
+                .map(|&(char, _, _)| packed_channel(bm_font, char))
+                .collect();
+            channels.sort_unstable();
+            channels.dedup();
+
+            channels
+                .into_iter()
+                .map(|channel| {
+                    let texture = match channel {
+                        Some(channel) => unpack_channel(&image, channel)?,
+                        None => image.clone(),
+                    };
+                    Ok((channel, texture))
+                })
+                .collect::<Result<Vec<_>, BmFontLoadError>>()?
+        } else {
+            vec![(None, image)]
+        };
 
-        let layout_handle =
-            load_context.add_labeled_asset(format!("layout_{page_no}"), atlas_layout);
-        atlas_layout_handles.push(layout_handle);
+        for (channel, texture) in textures {
+            let texture_index = image_handles.len();
+            let image_handle =
+                load_context.add_labeled_asset(format!("texture_{texture_index}"), texture);
+            image_handles.push(image_handle);
+
+            let mut atlas_layout = TextureAtlasLayout::new_empty(size);
+            for (_, &mut (_, rect, ref mut placement)) in
+                atlas_character_map
+                    .iter_mut()
+                    .filter(|&(_, &mut (char, _, _))| {
+                        char.page as usize == page_no && packed_channel(bm_font, char) == channel
+                    })
+            {
+                placement.replace((texture_index, atlas_layout.add_texture(rect)));
+            }
+
+            let layout_handle =
+                load_context.add_labeled_asset(format!("layout_{texture_index}"), atlas_layout);
+            atlas_layout_handles.push(layout_handle);
+        }
     }
 
     Ok((image_handles, atlas_layout_handles))
@@ -314,24 +539,28 @@ async fn load_images_and_textures(
 /// Constructs the final `ImageFont` asset.
 fn construct_image_font(
     image_handles: Vec<bevy::asset::Handle<Image>>,
-    atlas_character_map: HashMap<char, (&bmfont_rs::Char, URect, Option<usize>)>,
+    atlas_character_map: HashMap<char, (&bmfont_rs::Char, URect, Option<(usize, usize)>)>,
     atlas_layout_handles: Vec<bevy::asset::Handle<TextureAtlasLayout>>,
+    kerning_pairs: HashMap<(char, char), f32>,
+    fallback_character: Option<char>,
+    line_metrics: ImageFontLineMetrics,
     settings: &ImageFontLoaderSettings,
 ) -> ImageFont {
     ImageFont {
         textures: image_handles,
         atlas_character_map: atlas_character_map
             .into_iter()
-            .map(|(char, (font_char, _, atlas_index))| {
+            .map(|(char, (font_char, _, placement))| {
+                #[expect(
+                    clippy::unwrap_used,
+                    reason = "all Nones replaced in load_images_and_textures"
+                )]
+                let (page_index, character_index) = placement.unwrap();
                 (
                     char,
-                    #[expect(
-                        clippy::unwrap_used,
-                        reason = "all Nones replaced in load_images_and_textures"
-                    )]
                     ImageFontCharacter {
-                        character_index: atlas_index.unwrap(),
-                        page_index: font_char.page as usize,
+                        character_index,
+                        page_index,
                         offsets: Vec2::new(
                             f32::from(font_char.xoffset),
                             -f32::from(font_char.yoffset),
@@ -343,5 +572,191 @@ fn construct_image_font(
             .collect(),
         atlas_layouts: atlas_layout_handles,
         image_sampler: settings.image_sampler.clone(),
+        kerning_pairs,
+        fallback_character,
+        line_metrics: Some(line_metrics),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use bevy::asset::{Assets, RenderAssetUsages};
+    use bevy::render::render_resource::Extent3d;
+    use bevy_image::TextureFormatPixelInfo as _;
+    use bmfont_rs::{Font, Kerning};
+
+    use super::*;
+    use crate::tests::utils::{initialize_app_with_font, wait_until_loaded};
+
+    #[test]
+    fn process_bmf_characters_decodes_character_ids() {
+        let bm_font = Font {
+            chars: vec![
+                bmfont_rs::Char {
+                    id: 0x80,
+                    ..bmfont_rs::Char::default()
+                },
+                bmfont_rs::Char {
+                    id: 0xDB,
+                    ..bmfont_rs::Char::default()
+                },
+            ],
+            ..Font::default()
+        };
+
+        let (windows_1252, _) = process_bmf_characters(&bm_font, CharacterEncoding::Windows1252);
+        assert!(windows_1252.contains_key(&'€'));
+        assert!(windows_1252.contains_key(&'Û'));
+
+        let (cp437, _) = process_bmf_characters(&bm_font, CharacterEncoding::Cp437);
+        assert!(cp437.contains_key(&'Ç'));
+        assert!(cp437.contains_key(&'█'));
+    }
+
+    #[test]
+    fn process_bmf_characters_stores_invalid_glyph() {
+        let invalid_glyph = bmfont_rs::Char {
+            id: INVALID_GLYPH_ID,
+            x: 10,
+            ..bmfont_rs::Char::default()
+        };
+        let bm_font = Font {
+            chars: vec![
+                bmfont_rs::Char {
+                    id: 'A'.into(),
+                    ..bmfont_rs::Char::default()
+                },
+                invalid_glyph,
+            ],
+            ..Font::default()
+        };
+
+        let (atlas_character_map, invalid_glyph_character) =
+            process_bmf_characters(&bm_font, CharacterEncoding::Unicode);
+        assert_eq!(invalid_glyph_character, Some(INVALID_GLYPH_CHARACTER));
+        assert_eq!(atlas_character_map.len(), 2);
+        assert_eq!(
+            atlas_character_map[&INVALID_GLYPH_CHARACTER].0,
+            &invalid_glyph
+        );
+
+        // Fonts without an invalid glyph don't get a fallback character
+        let bm_font = Font {
+            chars: vec![bmfont_rs::Char::default()],
+            ..Font::default()
+        };
+        let (_, invalid_glyph_character) =
+            process_bmf_characters(&bm_font, CharacterEncoding::Unicode);
+        assert_eq!(invalid_glyph_character, None);
+    }
+
+    #[test]
+    fn packed_channel_from_chnl_selects_single_channels() {
+        assert_eq!(
+            PackedChannel::from_chnl(Chnl::RED),
+            Some(PackedChannel::Red)
+        );
+        assert_eq!(
+            PackedChannel::from_chnl(Chnl::GREEN),
+            Some(PackedChannel::Green)
+        );
+        assert_eq!(
+            PackedChannel::from_chnl(Chnl::BLUE),
+            Some(PackedChannel::Blue)
+        );
+        assert_eq!(
+            PackedChannel::from_chnl(Chnl::ALPHA),
+            Some(PackedChannel::Alpha)
+        );
+        assert_eq!(PackedChannel::from_chnl(Chnl::ALL), None);
+    }
+
+    #[test]
+    fn unpack_channel_extracts_channel_into_alpha() {
+        let image = Image::new(
+            Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![10, 20, 30, 40, 50, 60, 70, 80],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+
+        let green = unpack_channel(&image, PackedChannel::Green).unwrap();
+        assert_eq!(
+            green.data.unwrap(),
+            vec![255, 255, 255, 20, 255, 255, 255, 60]
+        );
+
+        let alpha = unpack_channel(&image, PackedChannel::Alpha).unwrap();
+        assert_eq!(
+            alpha.data.unwrap(),
+            vec![255, 255, 255, 40, 255, 255, 255, 80]
+        );
+    }
+
+    #[test]
+    fn loads_packed_font_with_a_texture_per_channel() {
+        let (mut app, handle) = initialize_app_with_font("bmf_packed.txt.fnt");
+        wait_until_loaded(&mut app, &handle);
+
+        let world = app.world();
+        let image_font = world.resource::<Assets<ImageFont>>().get(&handle).unwrap();
+        let images = world.resource::<Assets<Image>>();
+        let layouts = world.resource::<Assets<TextureAtlasLayout>>();
+        let glyph_pixel = |character| {
+            let image_font_character = &image_font.atlas_character_map[&character];
+            let page_index = image_font_character.page_index;
+            let texture = images.get(&image_font.textures[page_index]).unwrap();
+            let layout = layouts.get(&image_font.atlas_layouts[page_index]).unwrap();
+            let position = layout.textures[image_font_character.character_index].min;
+            let pixel_size = texture.texture_descriptor.format.pixel_size();
+            let offset = (position.y * texture.width() + position.x) as usize * pixel_size;
+
+            (
+                texture.texture_descriptor.format,
+                texture.data.as_ref().unwrap()[offset..offset + pixel_size].to_vec(),
+            )
+        };
+
+        // The page holds one glyph in each channel, which become white textures
+        // of their own, and one glyph in all channels, for which the page is
+        // used as it is
+        assert_eq!(image_font.textures.len(), 5);
+        for (character, coverage) in [('R', 10), ('G', 20), ('B', 30), ('A', 40)] {
+            assert_eq!(
+                glyph_pixel(character),
+                (TextureFormat::Rgba8UnormSrgb, vec![255, 255, 255, coverage]),
+                "{character}"
+            );
+        }
+        assert_eq!(
+            glyph_pixel('W'),
+            (TextureFormat::Rgba8UnormSrgb, vec![255, 255, 255, 255])
+        );
+    }
+
+    #[test]
+    fn process_bmf_kernings_maps_character_pairs() {
+        let bm_font = Font {
+            kernings: vec![
+                Kerning::new('A'.into(), 'V'.into(), -2),
+                Kerning::new('T'.into(), 'o'.into(), -4),
+                // Not a valid character and thus skipped
+                Kerning::new(0xD800, 'o'.into(), 1),
+            ],
+            ..Font::default()
+        };
+
+        let kerning_pairs = process_bmf_kernings(&bm_font, CharacterEncoding::Unicode);
+
+        assert_eq!(kerning_pairs.len(), 2);
+        assert_eq!(kerning_pairs.get(&('A', 'V')), Some(&-2.0));
+        assert_eq!(kerning_pairs.get(&('T', 'o')), Some(&-4.0));
     }
 }
@@ -0,0 +1,198 @@
+//! This module provides the [`CharacterEncoding`] type, which maps the
+//! character ids found in BMF font files to Unicode [`char`]s.
+//!
+//! Fonts exported with Unicode enabled use Unicode code points as their
+//! character ids. Fonts exported without it use the ids of a single-byte code
+//! page instead, declared by the `charset` of the font. The supported code
+//! pages are:
+//! - `ANSI` (and `DEFAULT`): Windows-1252, the Western European Windows code
+//!   page.
+//! - `OEM`: Code page 437, the character set of the original IBM PC.
+
+use bmfont_rs::{Charset, Info, ANSI, DEFAULT, OEM};
+
+/// The encoding of the character ids in a BMF font.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum CharacterEncoding {
+    /// Character ids are Unicode code points.
+    Unicode,
+    /// Character ids are Windows-1252 code points.
+    Windows1252,
+    /// Character ids are code page 437 code points.
+    Cp437,
+}
+
+impl CharacterEncoding {
+    /// Determines the encoding of a font's character ids from its info block.
+    ///
+    /// A non-Unicode font without a charset is assumed to use `ANSI`, which is
+    /// what the `BMFont` generator falls back to in that case. The `DEFAULT`
+    /// charset refers to the code page of the system the font was exported
+    /// on, which is taken to be Windows-1252 as well.
+    ///
+    /// # Parameters
+    /// - `info`: The info block of the font.
+    ///
+    /// # Returns
+    /// - `Some(CharacterEncoding)`: If the font's character set is supported.
+    /// - `None`: If the font uses a character set that can't be decoded.
+    pub(crate) fn from_info(info: &Info) -> Option<Self> {
+        if info.unicode {
+            return Some(Self::Unicode);
+        }
+
+        match info.charset {
+            Charset::Null | Charset::Tagged(ANSI | DEFAULT) => Some(Self::Windows1252),
+            Charset::Tagged(OEM) => Some(Self::Cp437),
+            Charset::Tagged(_) | Charset::Undefined(_) => None,
+        }
+    }
+
+    /// Decodes a character id into the [`char`] it represents.
+    ///
+    /// # Parameters
+    /// - `id`: The character id, as found in the font file.
+    ///
+    /// # Returns
+    /// - `Some(char)`: The character represented by `id`.
+    /// - `None`: If `id` doesn't represent a character in this encoding.
+    pub(crate) fn decode(self, id: u32) -> Option<char> {
+        match self {
+            Self::Unicode => char::from_u32(id),
+            Self::Windows1252 => {
+                let byte = u8::try_from(id).ok()?;
+                match byte {
+                    0x80..=0x9F => WINDOWS_1252_HIGH_CONTROLS[usize::from(byte - 0x80)],
+                    _ => Some(char::from(byte)),
+                }
+            }
+            Self::Cp437 => {
+                let byte = u8::try_from(id).ok()?;
+                match byte {
+                    0x01..=0x1F => Some(CP437_LOW_GRAPHICS[usize::from(byte - 0x01)]),
+                    0x7F => Some('⌂'),
+                    0x80..=0xFF => Some(CP437_HIGH[usize::from(byte - 0x80)]),
+                    _ => Some(char::from(byte)),
+                }
+            }
+        }
+    }
+}
+
+/// The characters of Windows-1252 in the range `0x80..=0x9F`, where it differs
+/// from Latin-1. Outside of this range, the two are identical. `None` marks
+/// the code points that aren't assigned a character.
+const WINDOWS_1252_HIGH_CONTROLS: [Option<char>; 32] = [
+    Some('€'),
+    None,
+    Some('‚'),
+    Some('ƒ'),
+    Some('„'),
+    Some('…'),
+    Some('†'),
+    Some('‡'),
+    Some('ˆ'),
+    Some('‰'),
+    Some('Š'),
+    Some('‹'),
+    Some('Œ'),
+    None,
+    Some('Ž'),
+    None,
+    None,
+    Some('\u{2018}'),
+    Some('\u{2019}'),
+    Some('\u{201c}'),
+    Some('\u{201d}'),
+    Some('•'),
+    Some('–'),
+    Some('—'),
+    Some('˜'),
+    Some('™'),
+    Some('š'),
+    Some('›'),
+    Some('œ'),
+    None,
+    Some('ž'),
+    Some('Ÿ'),
+];
+
+/// The graphical characters code page 437 displays for the code points
+/// `0x01..=0x1F`, which are control characters in ASCII.
+const CP437_LOW_GRAPHICS: [char; 31] = [
+    '☺', '☻', '♥', '♦', '♣', '♠', '•', '◘', '○', '◙', '♂', '♀', '♪', '♫', '☼', '►', '◄', '↕', '‼',
+    '¶', '§', '▬', '↨', '↑', '↓', '→', '←', '∟', '↔', '▲', '▼',
+];
+
+/// The characters of code page 437 in the range `0x80..=0xFF`.
+const CP437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', //
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', //
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', //
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', //
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', //
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', //
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', //
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}', //
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(unicode: bool, charset: Charset) -> Info {
+        Info {
+            unicode,
+            charset,
+            ..Info::default()
+        }
+    }
+
+    #[test]
+    fn from_info_detects_supported_encodings() {
+        assert_eq!(
+            CharacterEncoding::from_info(&info(true, Charset::Null)),
+            Some(CharacterEncoding::Unicode)
+        );
+        assert_eq!(
+            CharacterEncoding::from_info(&info(false, Charset::Null)),
+            Some(CharacterEncoding::Windows1252)
+        );
+        assert_eq!(
+            CharacterEncoding::from_info(&info(false, Charset::Tagged(ANSI))),
+            Some(CharacterEncoding::Windows1252)
+        );
+        assert_eq!(
+            CharacterEncoding::from_info(&info(false, Charset::Tagged(OEM))),
+            Some(CharacterEncoding::Cp437)
+        );
+        assert_eq!(
+            CharacterEncoding::from_info(&info(false, Charset::Tagged(bmfont_rs::SHIFTJIS))),
+            None
+        );
+    }
+
+    #[test]
+    fn decodes_windows_1252() {
+        let encoding = CharacterEncoding::Windows1252;
+        assert_eq!(encoding.decode(0x41), Some('A'));
+        assert_eq!(encoding.decode(0x80), Some('€'));
+        assert_eq!(encoding.decode(0x81), None);
+        assert_eq!(encoding.decode(0x93), Some('“'));
+        assert_eq!(encoding.decode(0xE9), Some('é'));
+        assert_eq!(encoding.decode(0x100), None);
+    }
+
+    #[test]
+    fn decodes_cp437() {
+        let encoding = CharacterEncoding::Cp437;
+        assert_eq!(encoding.decode(0x01), Some('☺'));
+        assert_eq!(encoding.decode(0x1F), Some('▼'));
+        assert_eq!(encoding.decode(0x41), Some('A'));
+        assert_eq!(encoding.decode(0x7F), Some('⌂'));
+        assert_eq!(encoding.decode(0x80), Some('Ç'));
+        assert_eq!(encoding.decode(0xDB), Some('█'));
+        assert_eq!(encoding.decode(0xFE), Some('■'));
+        assert_eq!(encoding.decode(0x100), None);
+    }
+}
@@ -1,8 +1,35 @@
 #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
 #![allow(clippy::expect_used, reason = "test code panics to indicate errors")]
 
+use bevy::asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
 use super::*;
 
 mod image_font_descriptor;
 mod image_font_layout;
 mod image_font_loader;
+
+/// Creates a transparent image with opaque pixels at the given coordinates.
+fn image_with_pixels(size: UVec2, pixels: &[(u32, u32)]) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    for &(x, y) in pixels {
+        image.set_color_at(x, y, Color::WHITE).unwrap();
+    }
+    image
+}
+
+/// Creates a fully transparent image.
+fn blank_image(size: UVec2) -> Image {
+    image_with_pixels(size, &[])
+}
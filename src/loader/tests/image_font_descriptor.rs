@@ -38,3 +38,145 @@ fn validation_rejects_empty_automatic_layout() {
         Err(ImageFontDescriptorValidationError::EmptyLayoutString)
     ));
 }
+
+#[test]
+fn validation_rejects_invalid_kerning_pair() {
+    let mut kerning = ImageFontKerning::default();
+    kerning.pairs.insert(String::from("ABC"), -1.0);
+
+    let invalid_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("some/path"),
+        ImageFontLayout::Automatic(String::from("A")),
+    )
+    .unwrap()
+    .with_kerning(kerning);
+
+    assert!(matches!(
+        invalid_descriptor,
+        Err(ImageFontDescriptorValidationError::InvalidKerningPair { ref pair }) if pair == "ABC"
+    ));
+}
+
+#[test]
+fn kerning_is_optional_and_parsed_from_ron() {
+    let descriptor: ImageFontDescriptor =
+        ron::from_str(r#"(image: "some/path", layout: Automatic("AVTo"))"#).unwrap();
+    assert!(descriptor.kerning().pairs.is_empty());
+    assert!(descriptor.kerning().classes.is_empty());
+
+    let descriptor: ImageFontDescriptor = ron::from_str(
+        r#"(
+            image: "some/path",
+            layout: Automatic("AVTo"),
+            kerning: (
+                pairs: { "AV": -2 },
+                classes: [(left: "AT", right: "Vo", amount: -1)],
+            ),
+        )"#,
+    )
+    .unwrap();
+
+    let kerning_pairs = descriptor.kerning.into_kerning_pairs();
+    assert_eq!(kerning_pairs.len(), 4);
+    // Pairs take precedence over classes
+    assert_eq!(kerning_pairs.get(&('A', 'V')), Some(&-2.0));
+    assert_eq!(kerning_pairs.get(&('A', 'o')), Some(&-1.0));
+    assert_eq!(kerning_pairs.get(&('T', 'V')), Some(&-1.0));
+    assert_eq!(kerning_pairs.get(&('T', 'o')), Some(&-1.0));
+}
+
+#[test]
+fn fallback_character_is_parsed() {
+    let descriptor: ImageFontDescriptor = ron::from_str(
+        r#"(image: "some/path", layout: Automatic("A?"), fallback_character: Some('?'))"#,
+    )
+    .unwrap();
+    assert_eq!(descriptor.fallback_character(), Some('?'));
+
+    let descriptor: ImageFontDescriptor =
+        ron::from_str(r#"(image: "some/path", layout: Automatic("A?"))"#).unwrap();
+    assert_eq!(descriptor.fallback_character(), None);
+}
+
+#[test]
+fn validation_rejects_invalid_additional_page() {
+    let invalid_descriptor: Result<ImageFontDescriptor, _> = ron::from_str(
+        r#"(
+            image: "some/path",
+            layout: Automatic("A"),
+            additional_pages: [(image: "", layout: Automatic("B"))],
+        )"#,
+    )
+    .map_err(ImageFontLoadError::from)
+    .and_then(|descriptor: ImageFontDescriptor| Ok(descriptor.validate().map(|()| descriptor)?));
+
+    assert!(
+        matches!(
+            invalid_descriptor,
+            Err(ImageFontLoadError::DescriptorValidationError(
+                ImageFontDescriptorValidationError::EmptyImagePath
+            ))
+        ),
+        "{invalid_descriptor:?}"
+    );
+}
+
+#[test]
+fn automatic_grid_sides_default_to_zero() {
+    let layout: ImageFontLayout = ron::from_str(
+        r#"AutomaticGrid(characters: "AB", margin: (left: 1), spacing: (horizontal: 2))"#,
+    )
+    .unwrap();
+
+    let ImageFontLayout::AutomaticGrid {
+        margin,
+        padding,
+        spacing,
+        ..
+    } = layout
+    else {
+        panic!("unexpected layout: {layout:?}");
+    };
+    assert_eq!(margin, Padding::new(0, 0, 0, 1));
+    assert_eq!(padding, Padding::default());
+    assert_eq!(spacing, Spacing::new(2, 0));
+}
+
+#[test]
+fn trim_glyphs_is_parsed() {
+    let descriptor: ImageFontDescriptor = ron::from_str(
+        r#"(image: "some/path", layout: Automatic("AB"), trim_glyphs: Some((min_width: 2)))"#,
+    )
+    .unwrap();
+    assert_eq!(descriptor.trim_glyphs(), Some(GlyphTrimming::new(2, 0.0)));
+
+    let descriptor: ImageFontDescriptor =
+        ron::from_str(r#"(image: "some/path", layout: Automatic("AB"))"#).unwrap();
+    assert_eq!(descriptor.trim_glyphs(), None);
+}
+
+#[test]
+fn glyph_metrics_are_parsed() {
+    let descriptor: ImageFontDescriptor = ron::from_str(
+        r#"(
+            image: "some/path",
+            layout: Automatic("gj"),
+            baseline: Some(9),
+            glyph_metrics: {
+                'g': (baseline: Some(6)),
+                'j': (offset: (-1, 0), x_advance: Some(3)),
+            },
+        )"#,
+    )
+    .unwrap();
+
+    assert_eq!(descriptor.baseline(), Some(9));
+    assert_eq!(
+        descriptor.glyph_metrics()[&'g'],
+        GlyphMetrics::new(Vec2::ZERO, None, Some(6))
+    );
+    assert_eq!(
+        descriptor.glyph_metrics()[&'j'],
+        GlyphMetrics::new(Vec2::new(-1., 0.), Some(3.), None)
+    );
+}
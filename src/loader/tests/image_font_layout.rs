@@ -8,7 +8,7 @@ fn layout_automatic() {
     // Each char is 100x100
     let image_size = UVec2::new(400, 300); // 4 characters wide, 3 rows
     let char_map = layout
-        .into_character_rect_map(image_size)
+        .into_character_rect_map(&blank_image(image_size))
         .expect("valid layout");
 
     assert_eq!(char_map.len(), 12);
@@ -37,7 +37,7 @@ fn layout_automatic() {
 fn layout_automatic_invalid_image_width() {
     let layout = ImageFontLayout::Automatic("AB\nCD".to_string());
     let image_size = UVec2::new(301, 200); // 301 / 2 is not a whole number
-    let result = layout.into_character_rect_map(image_size);
+    let result = layout.into_character_rect_map(&blank_image(image_size));
 
     assert!(matches!(
         result,
@@ -52,7 +52,7 @@ fn layout_automatic_invalid_image_width() {
 fn layout_automatic_invalid_image_height() {
     let layout = ImageFontLayout::Automatic("AB\nCD".to_string());
     let image_size = UVec2::new(300, 201); // 201 / 2 is not a whole number
-    let result = layout.into_character_rect_map(image_size);
+    let result = layout.into_character_rect_map(&blank_image(image_size));
 
     assert!(matches!(
         result,
@@ -69,7 +69,7 @@ fn layout_automatic_repeated_characters_error() {
     let layout = ImageFontLayout::Automatic("AB\nAC".to_string());
 
     let image_size = UVec2::new(200, 100); // Each cell is 100x100 pixels
-    let result = layout.into_character_rect_map(image_size);
+    let result = layout.into_character_rect_map(&blank_image(image_size));
 
     // Verify that an error is returned due to repeated characters
     assert!(
@@ -85,6 +85,173 @@ fn layout_automatic_repeated_characters_error() {
     );
 }
 
+/// Test `ImageFontLayout::AutomaticGrid` skips margins, spacing and padding.
+#[test]
+fn layout_automatic_grid() {
+    let layout = ImageFontLayout::AutomaticGrid {
+        characters: "ABC\nDEF".to_string(),
+        margin: Padding::new(2, 1, 2, 1),
+        padding: Padding::new(0, 1, 0, 1),
+        spacing: Spacing::new(1, 2),
+    };
+
+    // 1 + 3 * 10 + 2 * 1 + 1 wide, 2 + 2 * 12 + 2 + 2 high
+    let image_size = UVec2::new(34, 30);
+    let char_map = layout
+        .into_character_rect_map(&blank_image(image_size))
+        .expect("valid layout");
+
+    assert_eq!(char_map.len(), 6);
+    assert_eq!(char_map[&'A'], URect::new(2, 2, 10, 14));
+    assert_eq!(char_map[&'C'], URect::new(24, 2, 32, 14));
+    assert_eq!(char_map[&'E'], URect::new(13, 16, 21, 28));
+}
+
+#[test]
+fn layout_automatic_grid_invalid_image_width() {
+    let layout = ImageFontLayout::AutomaticGrid {
+        characters: "AB".to_string(),
+        margin: Padding::default(),
+        padding: Padding::default(),
+        spacing: Spacing::new(1, 0),
+    };
+    // Would fit without the spacing
+    let result = layout.into_character_rect_map(&blank_image(UVec2::new(20, 10)));
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLayoutValidationError::InvalidImageWidth {
+                width: 20,
+                per_line_character_count: 2
+            })
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn layout_automatic_grid_margin_larger_than_image() {
+    let layout = ImageFontLayout::AutomaticGrid {
+        characters: "AB".to_string(),
+        margin: Padding::uniform(6),
+        padding: Padding::default(),
+        spacing: Spacing::default(),
+    };
+    let result = layout.into_character_rect_map(&blank_image(UVec2::new(20, 10)));
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLayoutValidationError::InvalidImageHeight {
+                height: 10,
+                line_count: 1
+            })
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn layout_automatic_grid_padding_exceeds_cell() {
+    let layout = ImageFontLayout::AutomaticGrid {
+        characters: "AB".to_string(),
+        margin: Padding::default(),
+        padding: Padding::new(0, 6, 0, 6),
+        spacing: Spacing::default(),
+    };
+    let result = layout.into_character_rect_map(&blank_image(UVec2::new(20, 10)));
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLayoutValidationError::PaddingExceedsCell { cell_size })
+                if cell_size == UVec2::new(10, 10)
+        ),
+        "{result:?}"
+    );
+}
+
+/// Creates an image with two rows of glyphs separated by magenta lines; the
+/// first row has three glyphs, the second one has two.
+fn separated_image() -> Image {
+    let mut image = blank_image(UVec2::new(8, 7));
+    let separator = Color::srgb_u8(255, 0, 255);
+    let mut separator_pixels = Vec::new();
+    separator_pixels.extend((0..8).map(|x| (x, 3)));
+    separator_pixels.extend((0..3).flat_map(|y| [(2, y), (5, y)]));
+    separator_pixels.extend((4..7).flat_map(|y| [(0, y), (4, y)]));
+    for (x, y) in separator_pixels {
+        image.set_color_at(x, y, separator).unwrap();
+    }
+    image
+}
+
+/// Test `ImageFontLayout::Separated` finds the glyphs between separators.
+#[test]
+fn layout_separated() {
+    let layout = ImageFontLayout::Separated {
+        characters: "ABC\nDE".to_string(),
+        separator: [255, 0, 255, 255],
+    };
+
+    let char_map = layout
+        .into_character_rect_map(&separated_image())
+        .expect("valid layout");
+
+    assert_eq!(char_map.len(), 5);
+    assert_eq!(char_map[&'A'], URect::new(0, 0, 2, 3));
+    assert_eq!(char_map[&'B'], URect::new(3, 0, 5, 3));
+    assert_eq!(char_map[&'C'], URect::new(6, 0, 8, 3));
+    assert_eq!(char_map[&'D'], URect::new(1, 4, 4, 7));
+    assert_eq!(char_map[&'E'], URect::new(5, 4, 8, 7));
+}
+
+#[test]
+fn layout_separated_glyph_count_mismatch() {
+    let layout = ImageFontLayout::Separated {
+        characters: "ABCD".to_string(),
+        separator: [255, 0, 255, 255],
+    };
+
+    let result = layout.into_character_rect_map(&separated_image());
+
+    assert!(
+        matches!(
+            result,
+            Err(
+                ImageFontLayoutValidationError::SeparatedGlyphCountMismatch {
+                    glyph_count: 5,
+                    character_count: 4
+                }
+            )
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn layout_separated_repeated_characters_error() {
+    let layout = ImageFontLayout::Separated {
+        characters: "ABC\nDA".to_string(),
+        separator: [255, 0, 255, 255],
+    };
+
+    let result = layout.into_character_rect_map(&separated_image());
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLayoutValidationError::AutomaticRepeatedCharacter {
+                row: 1,
+                column: 1,
+                character: 'A'
+            })
+        ),
+        "{result:?}"
+    );
+}
+
 #[test]
 fn layout_manual_monospace() {
     let layout = ImageFontLayout::ManualMonospace {
@@ -96,7 +263,7 @@ fn layout_manual_monospace() {
     // independent of the actual size of the source texture
     let image_size = UVec2::new(100, 50);
     let char_map = layout
-        .into_character_rect_map(image_size)
+        .into_character_rect_map(&blank_image(image_size))
         .expect("valid layout");
 
     assert_eq!(char_map.len(), 2);
@@ -115,7 +282,7 @@ fn test_image_font_layout_manual() {
     // the actual size of the source texture
     let image_size = UVec2::new(100, 100);
     let char_map = layout
-        .into_character_rect_map(image_size)
+        .into_character_rect_map(&blank_image(image_size))
         .expect("valid layout");
 
     assert_eq!(char_map.len(), 2);
@@ -128,7 +295,7 @@ fn layout_manual_empty_map() {
     let layout = ImageFontLayout::Manual(HashMap::new());
     let image_size = UVec2::new(100, 100);
     let char_map = layout
-        .into_character_rect_map(image_size)
+        .into_character_rect_map(&blank_image(image_size))
         .expect("valid layout");
 
     assert!(
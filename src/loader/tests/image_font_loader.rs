@@ -46,7 +46,7 @@ async fn image_font_loader_read_and_validate_font_descriptor_fails_on_invalid_de
 }
 
 #[test]
-fn descriptor_to_character_map_and_layout_succeeds_on_valid_descriptor() {
+fn descriptor_to_character_map_and_layouts_succeeds_on_valid_descriptor() {
     let font_descriptor = ImageFontDescriptor::new(
         Utf8PathBuf::from("path/to/image.png"),
         ImageFontLayout::Automatic(String::from("ABCD")),
@@ -54,13 +54,14 @@ fn descriptor_to_character_map_and_layout_succeeds_on_valid_descriptor() {
     .expect("valid descriptor");
     let image_size = UVec2::new(100, 50);
 
-    let result = descriptor_to_character_map_and_layout(font_descriptor, image_size);
+    let result =
+        descriptor_to_character_map_and_layouts(font_descriptor, &[blank_image(image_size)]);
 
     assert!(result.is_ok());
 }
 
 #[test]
-fn descriptor_to_character_map_and_layout_fails_on_invalid_layout() {
+fn descriptor_to_character_map_and_layouts_fails_on_invalid_layout() {
     let font_descriptor = ImageFontDescriptor::new(
         Utf8PathBuf::from("path/to/image.png"),
         ImageFontLayout::Automatic(String::from("ABCD")),
@@ -68,10 +69,292 @@ fn descriptor_to_character_map_and_layout_fails_on_invalid_layout() {
     .expect("valid descriptor");
     let invalid_image_size = UVec2::new(101, 50); // Invalid dimensions
 
-    let result = descriptor_to_character_map_and_layout(font_descriptor, invalid_image_size);
+    let result = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(invalid_image_size)],
+    );
 
     assert!(
         matches!(result, Err(ImageFontLoadError::LayoutValidationError(_))),
         "{result:?}"
     );
 }
+
+#[test]
+fn descriptor_to_character_map_and_layouts_includes_kerning() {
+    let mut kerning = ImageFontKerning::default();
+    kerning.pairs.insert(String::from("AB"), -1.0);
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Automatic(String::from("ABCD")),
+    )
+    .and_then(|descriptor| descriptor.with_kerning(kerning))
+    .expect("valid descriptor");
+    let image_size = UVec2::new(100, 50);
+
+    let (_, _, kerning_pairs, _) =
+        descriptor_to_character_map_and_layouts(font_descriptor, &[blank_image(image_size)])
+            .unwrap();
+
+    assert_eq!(kerning_pairs.get(&('A', 'B')), Some(&-1.0));
+    assert_eq!(kerning_pairs.get(&('B', 'A')), None);
+}
+
+#[test]
+fn validate_fallback_character_requires_character_in_font() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Automatic(String::from("AB?")),
+    )
+    .expect("valid descriptor");
+    let (atlas_character_map, _, _, _) = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(UVec2::new(30, 10))],
+    )
+    .unwrap();
+
+    assert!(validate_fallback_character(None, &atlas_character_map).is_ok());
+    assert!(validate_fallback_character(Some('?'), &atlas_character_map).is_ok());
+    assert!(matches!(
+        validate_fallback_character(Some('!'), &atlas_character_map),
+        Err(ImageFontLoadError::MissingFallbackCharacter('!'))
+    ));
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_maps_additional_pages() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("letters.png"),
+        ImageFontLayout::Automatic(String::from("AB")),
+    )
+    .and_then(|descriptor| {
+        descriptor.with_additional_pages(vec![ImageFontPage::new(
+            Utf8PathBuf::from("symbols.png"),
+            ImageFontLayout::Automatic(String::from("+-*")),
+        )?])
+    })
+    .expect("valid descriptor");
+
+    let (atlas_character_map, layouts, _, _) = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[
+            blank_image(UVec2::new(20, 10)),
+            blank_image(UVec2::new(30, 5)),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(layouts.len(), 2);
+    assert_eq!(layouts[0].size, UVec2::new(20, 10));
+    assert_eq!(layouts[1].size, UVec2::new(30, 5));
+    assert_eq!(atlas_character_map[&'B'].page_index, 0);
+    let star = &atlas_character_map[&'*'];
+    assert_eq!(star.page_index, 1);
+    assert_eq!(
+        layouts[1].textures[star.character_index],
+        URect::new(20, 0, 30, 5)
+    );
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_fails_on_character_on_several_pages() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("letters.png"),
+        ImageFontLayout::Automatic(String::from("AB")),
+    )
+    .and_then(|descriptor| {
+        descriptor.with_additional_pages(vec![ImageFontPage::new(
+            Utf8PathBuf::from("more_letters.png"),
+            ImageFontLayout::Automatic(String::from("BC")),
+        )?])
+    })
+    .expect("valid descriptor");
+
+    let result = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[
+            blank_image(UVec2::new(20, 10)),
+            blank_image(UVec2::new(20, 10)),
+        ],
+    );
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLoadError::LayoutValidationError(
+                ImageFontLayoutValidationError::CharacterOnSeveralPages {
+                    page: 1,
+                    character: 'B'
+                }
+            ))
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn glyph_trimming_shrinks_rects_to_visible_columns() {
+    // Cells of 8x4 pixels; 'A' covers columns 2 to 4, 'B' only column 9
+    let image = image_with_pixels(UVec2::new(24, 4), &[(2, 0), (4, 3), (9, 1)]);
+    let mut layout = TextureAtlasLayout::new_empty(image.size());
+    for column in 0..3 {
+        layout.add_texture(URect::new(column * 8, 0, (column + 1) * 8, 4));
+    }
+
+    GlyphTrimming::new(3, 0.0)
+        .trim_atlas_layout(&mut layout, &image)
+        .unwrap();
+
+    assert_eq!(layout.textures[0], URect::new(2, 0, 5, 4));
+    // Widened to the minimum width
+    assert_eq!(layout.textures[1], URect::new(9, 0, 12, 4));
+    // Empty, like a space
+    assert_eq!(layout.textures[2], URect::new(16, 0, 19, 4));
+}
+
+#[test]
+fn glyph_trimming_keeps_blank_cells_without_min_width() {
+    // Cells of 8x4 pixels; 'A' covers columns 2 to 4, the second cell is empty
+    let image = image_with_pixels(UVec2::new(16, 4), &[(2, 0), (4, 3)]);
+    let mut layout = TextureAtlasLayout::new_empty(image.size());
+    for column in 0..2 {
+        layout.add_texture(URect::new(column * 8, 0, (column + 1) * 8, 4));
+    }
+
+    GlyphTrimming::default()
+        .trim_atlas_layout(&mut layout, &image)
+        .unwrap();
+
+    assert_eq!(layout.textures[0], URect::new(2, 0, 5, 4));
+    // Empty, like a space, so it keeps the width of its cell
+    assert_eq!(layout.textures[1], URect::new(8, 0, 16, 4));
+}
+
+#[test]
+fn glyph_trimming_stays_within_rect() {
+    let image = image_with_pixels(UVec2::new(8, 4), &[(6, 0)]);
+
+    let rect = GlyphTrimming::new(4, 0.0)
+        .trimmed_rect(URect::new(0, 0, 8, 4), &image)
+        .unwrap();
+
+    assert_eq!(rect, URect::new(6, 0, 8, 4));
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_applies_glyph_metrics() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Manual(HashMap::from([
+            ('a', URect::new(0, 0, 5, 8)),
+            // Twelve pixels high, with the baseline after the eighth row
+            ('g', URect::new(5, 0, 10, 12)),
+            ('i', URect::new(10, 0, 12, 8)),
+        ])),
+    )
+    .expect("valid descriptor")
+    .with_glyph_metrics(
+        HashMap::from([
+            ('g', GlyphMetrics::new(Vec2::ZERO, None, Some(8))),
+            ('i', GlyphMetrics::new(Vec2::new(1., 0.), Some(4.), None)),
+        ]),
+        None,
+    );
+
+    let (atlas_character_map, _, _, _) = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(UVec2::new(12, 12))],
+    )
+    .unwrap();
+
+    assert_eq!(atlas_character_map[&'a'].offsets, Vec2::ZERO);
+    assert_eq!(atlas_character_map[&'a'].x_advance, None);
+    // The baseline is the bottom of the tallest glyph without a baseline, 'a'
+    assert_eq!(atlas_character_map[&'g'].offsets, Vec2::ZERO);
+    assert_eq!(atlas_character_map[&'i'].offsets, Vec2::new(1., 0.));
+    assert_eq!(atlas_character_map[&'i'].x_advance, Some(4.));
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_aligns_glyphs_to_baseline() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Manual(HashMap::from([
+            // Trimmed tightly around the glyphs
+            ('g', URect::new(0, 0, 5, 7)),
+            ('.', URect::new(5, 0, 7, 2)),
+        ])),
+    )
+    .expect("valid descriptor")
+    .with_glyph_metrics(
+        HashMap::from([
+            ('g', GlyphMetrics::new(Vec2::ZERO, None, Some(4))),
+            ('.', GlyphMetrics::new(Vec2::new(0., -1.), None, Some(2))),
+        ]),
+        Some(9),
+    );
+
+    let (atlas_character_map, _, _, _) =
+        descriptor_to_character_map_and_layouts(font_descriptor, &[blank_image(UVec2::new(7, 7))])
+            .unwrap();
+
+    // Moved down by 9 - 4 pixels
+    assert_eq!(atlas_character_map[&'g'].offsets, Vec2::new(0., -5.));
+    // Moved down by 9 - 2 pixels, then up by one
+    assert_eq!(atlas_character_map[&'.'].offsets, Vec2::new(0., -6.));
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_fails_on_metrics_for_missing_character() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Automatic(String::from("AB")),
+    )
+    .expect("valid descriptor")
+    .with_glyph_metrics(HashMap::from([('C', GlyphMetrics::default())]), None);
+
+    let result = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(UVec2::new(20, 10))],
+    );
+
+    assert!(
+        matches!(
+            result,
+            Err(ImageFontLoadError::LayoutValidationError(
+                ImageFontLayoutValidationError::MetricsForMissingCharacter { character: 'C' }
+            ))
+        ),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn descriptor_to_character_map_and_layouts_creates_line_metrics() {
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Automatic(String::from("AB")),
+    )
+    .expect("valid descriptor");
+    let (_, _, _, line_metrics) = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(UVec2::new(20, 10))],
+    )
+    .unwrap();
+    assert_eq!(line_metrics, None);
+
+    let font_descriptor = ImageFontDescriptor::new(
+        Utf8PathBuf::from("path/to/image.png"),
+        ImageFontLayout::Automatic(String::from("AB")),
+    )
+    .expect("valid descriptor")
+    .with_line_height(Some(13));
+    let (_, _, _, line_metrics) = descriptor_to_character_map_and_layouts(
+        font_descriptor,
+        &[blank_image(UVec2::new(20, 10))],
+    )
+    .unwrap();
+    // The baseline defaults to the bottom of the tallest glyph
+    assert_eq!(line_metrics, Some(ImageFontLineMetrics::new(13, 10)));
+    assert_eq!(line_metrics.unwrap().descent(), 3);
+}
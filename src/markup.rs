@@ -0,0 +1,464 @@
+//! This module provides an opt-in markup language for styling parts of an
+//! [`ImageFontText`] without constructing [`ImageFontSpan`]s in code.
+//!
+//! Adding an [`ImageFontMarkup`] component to an entity makes the
+//! [`apply_image_font_markup`] system parse its markup into the `text` and
+//! `spans` of the entity's [`ImageFontText`] whenever it changes. The markup
+//! consists of plain text and tags in square brackets, which apply to the text
+//! up to the matching closing tag:
+//! - `[color=#ff0000]Fire[/color]` tints the enclosed text with a hex color,
+//!   given as `RGB`, `RGBA`, `RRGGBB` or `RRGGBBAA`.
+//! - `[font=icons]...[/font]` renders the enclosed text with the font
+//!   registered under the name `icons` in the [`ImageFontMarkupFonts`]
+//!   resource.
+//! - `[height=24]...[/height]` renders the enclosed text at the given font
+//!   height.
+//!
+//! Tags can be nested, in which case the innermost one takes precedence. A
+//! literal `[` is written as `[[`.
+//!
+//! Additionally, `[icon=coin]` inserts the icon registered under the name
+//! `coin` in the [`ImageFontMarkupFonts`] resource. It has no closing tag, and
+//! is styled by the tags enclosing it like the text around it.
+
+use std::mem;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use derive_setters::Setters;
+use thiserror::Error;
+use tracing::error;
+
+use crate::{ImageFont, ImageFontSpan, ImageFontText};
+
+/// Opts an entity into having the `text` and `spans` of its [`ImageFontText`]
+/// generated from markup.
+///
+/// Whenever this component or the [`ImageFontMarkupFonts`] resource changes,
+/// [`apply_image_font_markup`] replaces the text and spans of the entity's
+/// `ImageFontText` with the result of parsing `markup`. Any other changes made
+/// to them in the meantime are overwritten. See the [module
+/// documentation](self) for the supported tags.
+#[derive(Debug, Clone, Reflect, Default, Component, Setters)]
+#[setters(into)]
+#[require(ImageFontText)]
+#[non_exhaustive]
+pub struct ImageFontMarkup {
+    /// The markup the text and its spans are generated from.
+    pub markup: String,
+}
+
+impl ImageFontMarkup {
+    /// Creates a new `ImageFontMarkup` from a markup string.
+    ///
+    /// # Parameters
+    /// - `markup`: The markup the text and its spans are generated from.
+    #[must_use]
+    pub fn new(markup: impl Into<String>) -> Self {
+        Self {
+            markup: markup.into(),
+        }
+    }
+
+    /// Parses the markup into spans of text.
+    ///
+    /// # Parameters
+    /// - `fonts`: The fonts and icons that `[font=...]` and `[icon=...]` tags
+    ///   can refer to by name.
+    ///
+    /// # Returns
+    /// The runs of text between the tags, in order, each styled according to
+    /// the tags enclosing it. Empty runs are left out, so markup without any
+    /// tags results in a single span without a style of its own.
+    ///
+    /// # Errors
+    /// Returns an [`ImageFontMarkupError`] if the markup contains an unknown
+    /// or malformed tag, a tag with an invalid value, or if its opening and
+    /// closing tags don't match up.
+    pub fn to_spans(
+        &self,
+        fonts: &ImageFontMarkupFonts,
+    ) -> Result<Vec<ImageFontSpan>, ImageFontMarkupError> {
+        let mut spans = Vec::new();
+        let mut open_tags: Vec<(&str, ImageFontSpan)> = Vec::new();
+        let mut current = ImageFontSpan::default();
+        let mut rest = self.markup.as_str();
+
+        while let Some(tag_start) = rest.find('[') {
+            current.text.push_str(&rest[..tag_start]);
+            rest = &rest[tag_start + 1..];
+            if let Some(after_escape) = rest.strip_prefix('[') {
+                current.text.push('[');
+                rest = after_escape;
+                continue;
+            }
+
+            let tag_end = rest
+                .find(']')
+                .ok_or_else(|| ImageFontMarkupError::UnterminatedTag(rest.into()))?;
+            let tag = &rest[..tag_end];
+            rest = &rest[tag_end + 1..];
+
+            let enclosing_style = |open_tags: &Vec<(&str, ImageFontSpan)>| {
+                open_tags
+                    .last()
+                    .map(|open_tag| open_tag.1.clone())
+                    .unwrap_or_default()
+            };
+            let style = if let Some(name) = tag.strip_prefix('/') {
+                match open_tags.pop() {
+                    Some((open_name, _)) if open_name == name => {}
+                    _ => return Err(ImageFontMarkupError::UnexpectedClosingTag(name.into())),
+                }
+                enclosing_style(&open_tags)
+            } else {
+                let (name, value) = tag
+                    .split_once('=')
+                    .ok_or_else(|| ImageFontMarkupError::MissingValue(tag.into()))?;
+                if name == "icon" {
+                    let icon = fonts
+                        .icons
+                        .get(value)
+                        .ok_or_else(|| ImageFontMarkupError::UnknownIcon(value.into()))?;
+                    if let Some(font) = icon.font.as_ref() {
+                        let style = ImageFontSpan {
+                            text: String::new(),
+                            ..current.clone()
+                        };
+                        let finished = mem::replace(&mut current, style.clone());
+                        if !finished.text.is_empty() {
+                            spans.push(finished);
+                        }
+                        spans.push(ImageFontSpan {
+                            text: icon.character.into(),
+                            font: Some(font.clone()),
+                            ..style
+                        });
+                    } else {
+                        current.text.push(icon.character);
+                    }
+                    continue;
+                }
+
+                let mut style = enclosing_style(&open_tags);
+                match name {
+                    "color" => {
+                        let color = Srgba::hex(value)
+                            .map_err(|_| ImageFontMarkupError::InvalidColor(value.into()))?;
+                        style.color = Some(color.into());
+                    }
+                    "font" => {
+                        let font = fonts
+                            .fonts
+                            .get(value)
+                            .ok_or_else(|| ImageFontMarkupError::UnknownFont(value.into()))?;
+                        style.font = Some(font.clone());
+                    }
+                    "height" => {
+                        let font_height = value
+                            .parse::<f32>()
+                            .ok()
+                            .filter(|height| height.is_finite() && *height > 0.)
+                            .ok_or_else(|| ImageFontMarkupError::InvalidHeight(value.into()))?;
+                        style.font_height = Some(font_height);
+                    }
+                    _ => return Err(ImageFontMarkupError::UnknownTag(name.into())),
+                }
+                open_tags.push((name, style.clone()));
+                style
+            };
+
+            let finished = mem::replace(&mut current, style);
+            if !finished.text.is_empty() {
+                spans.push(finished);
+            }
+        }
+
+        if let Some(&(name, _)) = open_tags.last() {
+            return Err(ImageFontMarkupError::UnclosedTag(name.into()));
+        }
+        current.text.push_str(rest);
+        if !current.text.is_empty() {
+            spans.push(current);
+        }
+
+        Ok(spans)
+    }
+}
+
+/// The fonts and icons that `[font=...]` and `[icon=...]` tags in
+/// [`ImageFontMarkup`] can refer to, by name.
+#[derive(Debug, Clone, Reflect, Default, Resource)]
+#[reflect(Resource)]
+#[non_exhaustive]
+pub struct ImageFontMarkupFonts {
+    /// Maps the names used in markup to the fonts they refer to.
+    pub fonts: HashMap<String, Handle<ImageFont>>,
+    /// Maps the names used in markup to the icons they refer to.
+    pub icons: HashMap<String, ImageFontMarkupIcon>,
+}
+
+/// An icon that `[icon=...]` tags in [`ImageFontMarkup`] can insert into the
+/// text.
+///
+/// The icon is a glyph of an [`ImageFont`], usually one added at runtime with
+/// [`ImageFont::insert_atlas_glyph`] or [`ImageFont::insert_image_glyph`].
+#[derive(Debug, Clone, Reflect, PartialEq)]
+#[non_exhaustive]
+pub struct ImageFontMarkupIcon {
+    /// The character the icon's glyph is registered under.
+    pub character: char,
+    /// The font containing the icon's glyph. If `None`, the glyph is taken
+    /// from the font of the text around the icon or its fallback fonts.
+    pub font: Option<Handle<ImageFont>>,
+}
+
+impl ImageFontMarkupIcon {
+    /// Creates a new `ImageFontMarkupIcon`.
+    ///
+    /// # Parameters
+    /// - `character`: The character the icon's glyph is registered under.
+    /// - `font`: The font containing the icon's glyph, or `None` to take it
+    ///   from the font of the text around the icon.
+    #[must_use]
+    pub const fn new(character: char, font: Option<Handle<ImageFont>>) -> Self {
+        Self { character, font }
+    }
+}
+
+/// Errors that can occur when parsing an [`ImageFontMarkup`].
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImageFontMarkupError {
+    /// A `[` is not followed by a matching `]`. Contains the markup following
+    /// the `[`.
+    #[error("tag is missing its closing bracket: [{0}")]
+    UnterminatedTag(String),
+
+    /// An opening tag has no value, such as `[color]` instead of
+    /// `[color=#ff0000]`.
+    #[error("tag [{0}] is missing a value")]
+    MissingValue(String),
+
+    /// The tag isn't one of the supported tags.
+    #[error("unknown tag: {0}")]
+    UnknownTag(String),
+
+    /// The value of a `[color=...]` tag isn't a valid hex color.
+    #[error("invalid color: {0}")]
+    InvalidColor(String),
+
+    /// No font is registered in [`ImageFontMarkupFonts`] under the name given
+    /// in a `[font=...]` tag.
+    #[error("unknown font: {0}")]
+    UnknownFont(String),
+
+    /// No icon is registered in [`ImageFontMarkupFonts`] under the name given
+    /// in an `[icon=...]` tag.
+    #[error("unknown icon: {0}")]
+    UnknownIcon(String),
+
+    /// The value of a `[height=...]` tag isn't a positive number.
+    #[error("invalid height: {0}")]
+    InvalidHeight(String),
+
+    /// A closing tag doesn't match the innermost open tag.
+    #[error("unexpected closing tag: [/{0}]")]
+    UnexpectedClosingTag(String),
+
+    /// A tag is never closed.
+    #[error("tag is never closed: [{0}]")]
+    UnclosedTag(String),
+}
+
+/// System that generates the `text` and `spans` of every [`ImageFontText`]
+/// with an [`ImageFontMarkup`] from its markup, whenever the markup or the
+/// [`ImageFontMarkupFonts`] change.
+///
+/// The leading run of text that isn't enclosed by any tags becomes the
+/// `text`; everything after it becomes the `spans`. If the markup is invalid,
+/// an error is logged and the markup is shown as it is, without any styling.
+pub fn apply_image_font_markup(
+    mut query: Query<(Entity, Ref<ImageFontMarkup>, &mut ImageFontText)>,
+    fonts: Res<ImageFontMarkupFonts>,
+) {
+    for (entity, markup, mut image_font_text) in &mut query {
+        if !markup.is_changed() && !fonts.is_changed() {
+            continue;
+        }
+
+        let (text, spans) = match markup.to_spans(&fonts) {
+            Ok(spans) => {
+                let mut spans = spans.into_iter().peekable();
+                let text = spans
+                    .next_if(|span| {
+                        span.font.is_none()
+                            && span.color.is_none()
+                            && span.font_height.is_none()
+                            && span.effects.is_none()
+                    })
+                    .map(|span| span.text)
+                    .unwrap_or_default();
+                (text, spans.collect())
+            }
+            Err(error) => {
+                error!("Invalid image font markup for entity {entity}: {error}");
+                (markup.markup.clone(), Vec::new())
+            }
+        };
+        image_font_text.text = text;
+        image_font_text.spans = spans;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use super::*;
+
+    fn red() -> Color {
+        Srgba::hex("ff0000").unwrap().into()
+    }
+
+    #[test]
+    fn parses_nested_tags() {
+        let icons = Handle::default();
+        let fonts = ImageFontMarkupFonts {
+            fonts: [(String::from("icons"), icons.clone())]
+                .into_iter()
+                .collect(),
+            ..default()
+        };
+        let markup =
+            ImageFontMarkup::new("A [color=#f00]red [font=icons]*[/font][/color] [[sword]");
+
+        let spans = markup.to_spans(&fonts).unwrap();
+
+        assert_eq!(
+            spans,
+            vec![
+                ImageFontSpan::new("A "),
+                ImageFontSpan::new("red ").color(red()),
+                ImageFontSpan::new("*").color(red()).font(icons),
+                ImageFontSpan::new(" [sword]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_height() {
+        let markup = ImageFontMarkup::new("[height=24]Title[/height]");
+
+        let spans = markup.to_spans(&default()).unwrap();
+
+        assert_eq!(spans, vec![ImageFontSpan::new("Title").font_height(24.)]);
+    }
+
+    #[test]
+    fn parses_icons() {
+        let icons = Handle::default();
+        let fonts = ImageFontMarkupFonts {
+            icons: [
+                (
+                    String::from("coin"),
+                    ImageFontMarkupIcon::new('\u{e000}', Some(icons.clone())),
+                ),
+                (
+                    String::from("a"),
+                    ImageFontMarkupIcon::new('\u{e001}', None),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..default()
+        };
+        let markup = ImageFontMarkup::new("Press [icon=a] for [color=#f00]5[icon=coin][/color]");
+
+        let spans = markup.to_spans(&fonts).unwrap();
+
+        assert_eq!(
+            spans,
+            vec![
+                ImageFontSpan::new("Press \u{e001} for "),
+                ImageFontSpan::new("5").color(red()),
+                ImageFontSpan::new("\u{e000}").color(red()).font(icons),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_markup() {
+        let fonts = ImageFontMarkupFonts::default();
+        let error = |markup: &str| ImageFontMarkup::new(markup).to_spans(&fonts).unwrap_err();
+
+        assert_eq!(
+            error("[color=#f00"),
+            ImageFontMarkupError::UnterminatedTag("color=#f00".into())
+        );
+        assert_eq!(
+            error("[color]A[/color]"),
+            ImageFontMarkupError::MissingValue("color".into())
+        );
+        assert_eq!(
+            error("[size=2]A[/size]"),
+            ImageFontMarkupError::UnknownTag("size".into())
+        );
+        assert_eq!(
+            error("[color=fire]A[/color]"),
+            ImageFontMarkupError::InvalidColor("fire".into())
+        );
+        assert_eq!(
+            error("[font=icons]A[/font]"),
+            ImageFontMarkupError::UnknownFont("icons".into())
+        );
+        assert_eq!(
+            error("[icon=coin]"),
+            ImageFontMarkupError::UnknownIcon("coin".into())
+        );
+        assert_eq!(
+            error("[height=-1]A[/height]"),
+            ImageFontMarkupError::InvalidHeight("-1".into())
+        );
+        assert_eq!(
+            error("[color=#f00]A[/font]"),
+            ImageFontMarkupError::UnexpectedClosingTag("font".into())
+        );
+        assert_eq!(
+            error("[color=#f00]A"),
+            ImageFontMarkupError::UnclosedTag("color".into())
+        );
+    }
+
+    #[test]
+    fn applies_markup_to_text() {
+        let mut app = App::new();
+        app.init_resource::<ImageFontMarkupFonts>();
+        app.add_systems(Update, apply_image_font_markup);
+        let entity = app
+            .world_mut()
+            .spawn(ImageFontMarkup::new("You found [color=#f00]Fire[/color]!"))
+            .id();
+        app.update();
+
+        let image_font_text = app.world().get::<ImageFontText>(entity).unwrap();
+        assert_eq!(image_font_text.text, "You found ");
+        assert_eq!(
+            image_font_text.spans,
+            vec![
+                ImageFontSpan::new("Fire").color(red()),
+                ImageFontSpan::new("!")
+            ]
+        );
+
+        // Invalid markup is shown as it is
+        app.world_mut()
+            .get_mut::<ImageFontMarkup>(entity)
+            .unwrap()
+            .markup = String::from("[color=#f00]Fire");
+        app.update();
+
+        let image_font_text = app.world().get::<ImageFontText>(entity).unwrap();
+        assert_eq!(image_font_text.text, "[color=#f00]Fire");
+        assert!(image_font_text.spans.is_empty());
+    }
+}
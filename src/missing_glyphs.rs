@@ -0,0 +1,314 @@
+//! This module provides diagnostics for text containing characters that its
+//! [`ImageFont`] has no glyphs for.
+//!
+//! Such characters are left out of the rendered text, or replaced by the font's
+//! `fallback_character`, which makes them easy to miss. Characters that are
+//! taken from one of the text's `fallback_fonts` instead don't count as
+//! missing. The text of every span is checked against the span's own font.
+//! Whenever an [`ImageFontText`] changes, the [`report_missing_glyphs`] system
+//! checks its text against its font and
+//! - sends an [`ImageFontMissingGlyphs`] event listing the missing characters
+//!   of each font, which can be used to e.g. fail a localization test run, and
+//! - logs a warning the first time each character is found to be missing from
+//!   each font.
+
+use std::iter;
+
+use bevy::{platform::collections::HashSet, prelude::*};
+use tracing::warn;
+
+use crate::{ImageFont, ImageFontText};
+
+/// Sent whenever an [`ImageFontText`] is changed or its font is (re)loaded,
+/// and its text contains characters that neither its [`ImageFont`] nor any of
+/// its fallback fonts have glyphs for.
+///
+/// Characters of a span with a [`font`](crate::ImageFontSpan::font) of its own
+/// are looked up in that font instead, so a text whose spans use several fonts
+/// sends an event for every font that is missing characters.
+///
+/// Line breaks (`\n` and `\r`) are never considered missing.
+#[derive(Debug, Clone, Event)]
+#[non_exhaustive]
+pub struct ImageFontMissingGlyphs {
+    /// The entity with the [`ImageFontText`] component.
+    pub entity: Entity,
+    /// The font the characters are missing from. This is the font of the
+    /// text, or that of the spans the characters are in.
+    pub font: Handle<ImageFont>,
+    /// The characters of the text and its spans rendered with `font` that
+    /// aren't in it or the text's fallback fonts, in the order they first
+    /// appear in the text. Every character is listed only once.
+    pub characters: Vec<char>,
+}
+
+impl ImageFont {
+    /// Determines which characters of a text this font has no glyphs for.
+    ///
+    /// # Parameters
+    /// - `text`: The text to check.
+    ///
+    /// # Returns
+    /// The characters of `text` that aren't in `atlas_character_map`, in the
+    /// order they first appear in `text`, without duplicates. Line breaks
+    /// (`\n` and `\r`) are skipped.
+    #[must_use]
+    pub fn missing_characters(&self, text: &str) -> Vec<char> {
+        let mut missing_characters = Vec::new();
+        for character in text.chars() {
+            if !matches!(character, '\n' | '\r')
+                && !self.atlas_character_map.contains_key(&character)
+                && !missing_characters.contains(&character)
+            {
+                missing_characters.push(character);
+            }
+        }
+        missing_characters
+    }
+}
+
+/// System that sends an [`ImageFontMissingGlyphs`] event for every changed
+/// [`ImageFontText`] whose text contains characters its font doesn't have.
+/// The characters of spans with a font of their own are checked against that
+/// font, and reported with it.
+///
+/// Additionally, a warning is logged the first time a character is found to be
+/// missing from a font; later occurrences of the same character with the same
+/// font are only reported through the event.
+///
+/// Texts whose font or any of whose fallback fonts aren't loaded yet are
+/// skipped, as characters missing from the font may still be found in the
+/// fallback fonts. They are checked once the fonts finish loading, as
+/// [`sync_texts_with_font_changes`] marks them as changed.
+///
+/// [`sync_texts_with_font_changes`]: crate::sync_texts_with_font_changes
+pub fn report_missing_glyphs(
+    query: Query<(Entity, &ImageFontText), Changed<ImageFontText>>,
+    image_fonts: Res<Assets<ImageFont>>,
+    mut events: EventWriter<ImageFontMissingGlyphs>,
+    mut reported: Local<HashSet<(AssetId<ImageFont>, char)>>,
+) {
+    for (entity, image_font_text) in &query {
+        if !iter::once(&image_font_text.font)
+            .chain(&image_font_text.fallback_fonts)
+            .all(|font| image_fonts.contains(font))
+        {
+            continue;
+        }
+
+        // The missing characters of every font, in the order the fonts are
+        // first used
+        let mut missing_glyphs: Vec<(&Handle<ImageFont>, Vec<char>)> = Vec::new();
+        let sections = iter::once((&image_font_text.text, &image_font_text.font)).chain(
+            image_font_text.spans.iter().map(|span| {
+                (
+                    &span.text,
+                    span.font.as_ref().unwrap_or(&image_font_text.font),
+                )
+            }),
+        );
+        for (text, font_handle) in sections {
+            // Spans whose font isn't loaded yet are checked once it is
+            let Some(span_font) = image_fonts.get(font_handle) else {
+                continue;
+            };
+            let font_chain = image_font_text.font_chain(span_font, &image_fonts);
+            let index = missing_glyphs
+                .iter()
+                .position(|&(handle, _)| handle.id() == font_handle.id())
+                .unwrap_or_else(|| {
+                    missing_glyphs.push((font_handle, Vec::new()));
+                    missing_glyphs.len() - 1
+                });
+            let characters = &mut missing_glyphs[index].1;
+            for character in span_font.missing_characters(text) {
+                if !characters.contains(&character)
+                    && !font_chain
+                        .iter()
+                        .any(|image_font| image_font.atlas_character_map.contains_key(&character))
+                {
+                    characters.push(character);
+                }
+            }
+        }
+
+        for (font_handle, characters) in missing_glyphs {
+            if characters.is_empty() {
+                continue;
+            }
+
+            let font_id = font_handle.id();
+            for &character in &characters {
+                if reported.insert((font_id, character)) {
+                    warn!(
+                        "Image font {font_id} has no glyph for character {character:?} (U+{:04X}), \
+                        used by entity {entity}",
+                        u32::from(character)
+                    );
+                }
+            }
+
+            events.write(ImageFontMissingGlyphs {
+                entity,
+                font: font_handle.clone(),
+                characters,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+    use super::*;
+    use crate::tests::utils::{initialize_app_with_loaded_example_font, ExampleFont};
+    use crate::ImageFontSpan;
+
+    #[test]
+    fn missing_characters_are_deduplicated_and_skip_line_breaks() {
+        let image_font = ImageFont::default();
+
+        assert_eq!(
+            image_font.missing_characters("abca\r\nb"),
+            vec!['a', 'b', 'c']
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    fn sends_event_for_missing_glyphs() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        let complete = app
+            .world_mut()
+            .spawn(ImageFontText::default().text("Hello").font(handle.clone()))
+            .id();
+        let incomplete = app
+            .world_mut()
+            .spawn(
+                ImageFontText::default()
+                    .text("Héllö é")
+                    .font(handle.clone()),
+            )
+            .id();
+        app.update();
+
+        let events: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<ImageFontMissingGlyphs>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, incomplete);
+        assert_eq!(events[0].font, handle);
+        assert_eq!(events[0].characters, vec!['é', 'ö']);
+
+        // Unchanged texts aren't checked again
+        app.update();
+        assert!(app
+            .world()
+            .resource::<Events<ImageFontMissingGlyphs>>()
+            .is_empty());
+
+        app.world_mut()
+            .get_mut::<ImageFontText>(complete)
+            .unwrap()
+            .text = String::from("Ünïcode");
+        app.update();
+        let events: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<ImageFontMissingGlyphs>>()
+            .drain()
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, complete);
+        assert_eq!(events[0].characters, vec!['Ü', 'ï']);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    fn waits_for_fallback_fonts_to_load() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        let fallback_font = app.world().resource::<Assets<ImageFont>>().reserve_handle();
+        let entity = app
+            .world_mut()
+            .spawn(
+                ImageFontText::default()
+                    .text("Aéö")
+                    .font(handle.clone())
+                    .fallback_fonts(vec![fallback_font.clone()]),
+            )
+            .id();
+        app.update();
+
+        // The fallback font might still have the characters
+        assert!(app
+            .world()
+            .resource::<Events<ImageFontMissingGlyphs>>()
+            .is_empty());
+
+        {
+            let mut image_fonts = app.world_mut().resource_mut::<Assets<ImageFont>>();
+            let mut image_font = image_fonts.get(&handle).unwrap().clone();
+            let glyph = image_font.atlas_character_map[&'A'].clone();
+            image_font.atlas_character_map.insert('é', glyph);
+            image_fonts.insert(&fallback_font, image_font);
+        }
+        app.world_mut()
+            .get_mut::<ImageFontText>(entity)
+            .unwrap()
+            .set_changed();
+        app.update();
+
+        let events: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<ImageFontMissingGlyphs>>()
+            .drain()
+            .map(|event| (event.entity, event.font, event.characters))
+            .collect();
+        assert_eq!(events, vec![(entity, handle, vec!['ö'])]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+    fn reports_missing_glyphs_of_span_fonts_with_their_font() {
+        let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+        let span_font = {
+            let mut image_fonts = app.world_mut().resource_mut::<Assets<ImageFont>>();
+            let mut span_font = image_fonts.get(&handle).unwrap().clone();
+            span_font
+                .atlas_character_map
+                .retain(|&character, _| character == 'A');
+            image_fonts.add(span_font)
+        };
+        let entity = app
+            .world_mut()
+            .spawn(
+                ImageFontText::default()
+                    .text("Aé")
+                    .font(handle.clone())
+                    .spans(vec![
+                        ImageFontSpan::new("AC").font(span_font.clone()),
+                        ImageFontSpan::new("Cö"),
+                    ]),
+            )
+            .id();
+        app.update();
+
+        // 'C' is only missing from the span's font, while the span without a
+        // font of its own is checked against the text's font
+        let events: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<ImageFontMissingGlyphs>>()
+            .drain()
+            .map(|event| (event.entity, event.font, event.characters))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (entity, handle, vec!['é', 'ö']),
+                (entity, span_font, vec!['C']),
+            ]
+        );
+    }
+}
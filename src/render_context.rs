@@ -18,8 +18,9 @@
 //! duplication and makes rendering logic more maintainable.
 //!
 //! # Key Features
-//! - **Asset Management**: Fetches and stores references to the font and
-//!   texture atlas assets.
+//! - **Asset Management**: Fetches and stores references to the fonts and
+//!   texture atlas assets. Characters missing from the text's font are looked
+//!   up in its fallback fonts.
 //! - **Text Calculations**: Computes text dimensions, glyph dimensions, and
 //!   scaling factors.
 //! - **Unified Rendering Support**: Handles both `atlas_sprites` (sprite-based)
@@ -31,41 +32,45 @@
 //! and is designed to work seamlessly with other components, such as
 //! `SpriteContext`.
 
+#[cfg(feature = "atlas_sprites")]
 mod anchors;
 mod filtered_string;
+mod text_layout;
 
-use std::cell::Cell;
+use std::cell::{Cell, OnceCell};
 use std::fmt::Debug;
 
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 use tracing::error;
 
+#[cfg(feature = "atlas_sprites")]
 use crate::render_context::anchors::{AnchorExt as _, AnchorOffsets, ComputeTransformParams};
 use crate::render_context::filtered_string::FilteredString;
-use crate::FontScalingMode;
-use crate::{ImageFont, ImageFontText};
+use crate::render_context::text_layout::{wrap_words, TextLayout, TextLine};
+pub(crate) use crate::render_context::text_layout::{PlacedGlyph, SpanCharacter};
+use crate::{FontHeightReference, FontScalingMode};
+use crate::{ImageFont, ImageFontCharacter, ImageFontText};
 
 /// Groups font-related assets and configuration for rendering text sprites.
 ///
-/// Includes references to the texture atlas layout, font asset, and the
+/// Includes references to the texture atlas layouts, font assets, and the
 /// font text component that defines the text content and font height.
 pub(crate) struct RenderContext<'assets> {
-    /// The texture atlas layout defining glyph placements.
-    atlas_layouts: Vec<&'assets TextureAtlasLayout>,
-    /// The font asset containing glyph metadata.
-    image_font: &'assets ImageFont,
+    /// The sections the text consists of: its own `text`, followed by one
+    /// entry per span in its `spans`. Glyphs refer to these by their
+    /// [`SpanCharacter::span_index`].
+    spans: Vec<SpanContext<'assets>>,
     /// The text component defining the content and font height.
     image_font_text: &'assets ImageFontText,
     /// Configuration for rendering the text, including anchor alignment,
     /// color, letter spacing, and scaling behavior.
     pub render_config: RenderConfig,
-    /// The text filtered to include only supported characters in the font
-    /// atlas.
-    filtered_text: FilteredString<'assets, &'assets String>,
 
     /// Cached maximum glyph height.
     max_height: CacheCell<u32>,
+    /// Cached layout of the text, split into lines.
+    layout: OnceCell<TextLayout>,
 }
 
 impl<'assets> RenderContext<'assets> {
@@ -76,7 +81,9 @@ impl<'assets> RenderContext<'assets> {
     /// for rendering.
     ///
     /// # Parameters
-    /// - `image_font`: A reference to the loaded `ImageFont` asset.
+    /// - `image_font`: The loaded asset of the text's font.
+    /// - `image_fonts`: The font assets to look up the text's fallback fonts
+    ///   and the fonts of its spans in.
     /// - `image_font_text`: A reference to the `ImageFontText` component
     ///   containing the text.
     /// - `render_config`: Rendering options such as color, anchor alignment,
@@ -89,40 +96,48 @@ impl<'assets> RenderContext<'assets> {
     /// - `None`: If the font or texture atlas layouts are missing.
     pub(crate) fn new(
         image_font: &'assets ImageFont,
+        image_fonts: &'assets Assets<ImageFont>,
         image_font_text: &'assets ImageFontText,
         render_config: RenderConfig,
         texture_atlas_layouts: &'assets Assets<TextureAtlasLayout>,
     ) -> Option<Self> {
-        let atlas_layouts: Result<Vec<_>, _> = image_font
-            .atlas_layouts
-            .iter()
-            .map(|texture_atlas_layout| {
-                texture_atlas_layouts
-                    .get(texture_atlas_layout)
-                    .ok_or_else(|| {
-                        format!("TextureAtlasLayout not loaded: {texture_atlas_layout:?}")
-                    })
-            })
-            .collect();
+        let font_chain = image_font_text.font_chain(image_font, image_fonts);
 
-        let atlas_layouts = match atlas_layouts {
-            Ok(layout) => layout,
-            Err(error) => {
-                error!("{error}");
-                return None;
-            }
-        };
-
-        let filtered_text = image_font.filter_string(&image_font_text.text);
+        let mut spans = vec![SpanContext::new(
+            font_chain.clone(),
+            &image_font_text.text,
+            image_font_text.font_height,
+            None,
+            texture_atlas_layouts,
+        )?];
+        for span in &image_font_text.spans {
+            let (span_font_chain, uses_text_font) = match span.font.as_ref() {
+                Some(font) if *font != image_font_text.font => (
+                    image_fonts.get(font).map_or_else(Vec::new, |span_font| {
+                        image_font_text.font_chain(span_font, image_fonts)
+                    }),
+                    false,
+                ),
+                _ => (font_chain.clone(), true),
+            };
+            let mut span_context = SpanContext::new(
+                span_font_chain,
+                &span.text,
+                span.font_height.or(image_font_text.font_height),
+                span.color,
+                texture_atlas_layouts,
+            )?;
+            span_context.uses_text_font = uses_text_font;
+            spans.push(span_context);
+        }
 
         Some(RenderContext {
-            atlas_layouts,
-            image_font,
+            spans,
             image_font_text,
             render_config,
-            filtered_text,
 
             max_height: default(),
+            layout: default(),
         })
     }
 
@@ -133,56 +148,262 @@ impl<'assets> RenderContext<'assets> {
     ///
     /// # Returns
     /// An `f32` representing the uniform scaling factor for text sprites.
+    #[inline]
+    pub(crate) fn scale(&self) -> f32 {
+        self.span_scale(0)
+    }
+
+    /// Computes the scaling factor for the glyphs of a single span of the
+    /// text.
+    ///
+    /// This is the span's font height divided by the height its glyphs are
+    /// scaled relative to; see [`reference_height`](Self::reference_height).
+    ///
+    /// # Parameters
+    /// - `span_index`: The index of the span.
+    ///
+    /// # Returns
+    /// The factor the span's glyphs are scaled by, which is `1.0` if neither
+    /// the span nor the text has a font height.
     #[expect(
         clippy::cast_precision_loss,
         reason = "`max_height` won't ever be particularly large"
     )]
     #[inline]
-    pub(crate) fn scale(&self) -> f32 {
-        let max_height = self.max_height();
-        self.image_font_text
+    fn span_scale(&self, span_index: usize) -> f32 {
+        let reference_height = self.reference_height(span_index);
+        self.spans[span_index]
             .font_height
-            .map_or(1.0, |font_height| font_height / max_height as f32)
+            .map_or(1.0, |font_height| font_height / reference_height as f32)
     }
 
-    /// Calculates the maximum height of the filtered text.
+    /// Computes how much larger the glyphs of a span are rendered than those
+    /// of the text's own `text`.
     ///
-    /// Iterates over the filtered text characters to determine the overall
-    /// height based on glyph sizes in the texture atlas.
+    /// # Parameters
+    /// - `span_index`: The index of the span.
     ///
     /// # Returns
-    /// The height of the tallest glyph
+    /// The ratio between the span's scale and the text's
+    /// [`scale`](Self::scale), or `1.0` if the text is scaled down to nothing.
+    #[inline]
+    pub(crate) fn relative_scale(&self, span_index: usize) -> f32 {
+        let relative_scale = self.span_scale(span_index) / self.scale();
+        if relative_scale.is_finite() {
+            relative_scale
+        } else {
+            1.0
+        }
+    }
+
+    /// Determines the native height the glyphs of a span are scaled relative
+    /// to.
+    ///
+    /// Spans rendered with the text's own font share its
+    /// [`max_height`](Self::max_height). Spans with a font of their own use
+    /// that font's line height, or the height of its tallest glyph that the
+    /// text's `font_height_reference` refers to.
+    #[inline]
+    fn reference_height(&self, span_index: usize) -> u32 {
+        let span = &self.spans[span_index];
+        if span.uses_text_font {
+            return self.max_height();
+        }
+        span.reference_height
+            .get_or_insert_with(|| span.native_height(self.image_font_text.font_height_reference))
+    }
+
+    /// Calculates the height of a line of the text at the font's native
+    /// height, which the text is scaled and anchored by.
+    ///
+    /// If the text's font has [`line_metrics`](ImageFont::line_metrics), this
+    /// is its line height, so that it doesn't depend on the text. Otherwise,
+    /// iterates over the glyphs the text's `font_height_reference` refers to,
+    /// i.e. those of the filtered text or of the whole font, to determine the
+    /// overall height based on glyph sizes in the texture atlas. Spans with a
+    /// font of their own aren't taken into account.
+    ///
+    /// # Returns
+    /// The font's line height if it has one, and the height of the tallest
+    /// glyph otherwise.
     #[inline]
     pub(crate) fn max_height(&self) -> u32 {
         self.max_height.get_or_insert_with(|| {
-            let mut max_height = 1;
+            let font_height_reference = self.image_font_text.font_height_reference;
+            self.spans
+                .iter()
+                .filter(|span| span.uses_text_font)
+                .map(|span| span.native_height(font_height_reference))
+                .fold(1, u32::max)
+        })
+    }
 
-            for character in self.filtered_text.filtered_chars() {
-                let image_font_character = &self.image_font.atlas_character_map[&character];
-                let rect = self.atlas_layouts[image_font_character.page_index].textures
-                    [image_font_character.character_index];
-                max_height = max_height.max(rect.height());
-            }
+    /// Returns the distance between the tops of two consecutive lines, at the
+    /// font's native height.
+    ///
+    /// This is the `line_height` of the [`ImageFontText`] if set, and
+    /// [`max_height`](Self::max_height) otherwise.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "`max_height` won't ever be particularly large"
+    )]
+    #[inline]
+    pub(crate) fn line_height(&self) -> f32 {
+        self.image_font_text
+            .line_height
+            .unwrap_or_else(|| self.max_height() as f32)
+    }
 
-            max_height
-        })
+    /// Calculates the total height of the text block at the font's native
+    /// height.
+    ///
+    /// For a single line, this is [`max_height`](Self::max_height). Every
+    /// additional line adds one [`line_height`](Self::line_height).
+    ///
+    /// # Returns
+    /// The unscaled height of the whole text block.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "neither the line count nor `max_height` will ever be particularly large"
+    )]
+    #[inline]
+    pub(crate) fn text_height(&self) -> f32 {
+        let extra_lines = self.layout().lines.len().saturating_sub(1);
+        self.max_height() as f32 + extra_lines as f32 * self.line_height()
     }
 
     /// Calculates the total width of the rendered text based on the filtered
     /// characters and glyph dimensions stored in the context.
     ///
+    /// For text spanning multiple lines, this is the width of the widest line.
+    ///
     /// # Returns
     /// The total width of the rendered text, in pixels, after applying the
     /// scaling factor.
     #[inline]
     pub(crate) fn text_width(&self) -> f32 {
-        let mut text_width = 0.;
+        self.layout().width()
+    }
+
+    /// Returns the layout of the text, computing it on first access.
+    ///
+    /// The layout splits the filtered text into lines at each `\n`, wraps
+    /// lines that exceed the text's `max_width` (if any) and determines the
+    /// x-position of every glyph within its line, taking the text's
+    /// `line_alignment` into account.
+    pub(crate) fn layout(&self) -> &TextLayout {
+        self.layout.get_or_init(|| {
+            let mut lines = Vec::new();
+            for paragraph in self.filtered_lines() {
+                if let Some(max_width) = self.wrap_width() {
+                    let wrapped =
+                        wrap_words(paragraph.into_iter(), max_width, |previous, character| {
+                            previous.map_or(0., |previous| self.kerning(previous, character))
+                                + self.character_advance(character)
+                        });
+                    for line in wrapped {
+                        lines.push(self.layout_line(lines.len(), line.into_iter()));
+                    }
+                } else {
+                    lines.push(self.layout_line(lines.len(), paragraph.into_iter()));
+                }
+                if let Some(last_line) = lines.last_mut() {
+                    last_line.ends_paragraph = true;
+                }
+            }
+            let mut layout = TextLayout { lines };
+            layout.align(self.image_font_text.line_alignment);
+            layout
+        })
+    }
+
+    /// Splits the filtered characters of all spans into lines at each `\n`.
+    ///
+    /// Each span continues the line the previous one ended on. Spans whose
+    /// font isn't loaded are left out.
+    ///
+    /// # Returns
+    /// The characters of each line, in order. There is always at least one
+    /// line, even for empty text.
+    fn filtered_lines(&self) -> Vec<Vec<SpanCharacter>> {
+        let mut lines = vec![Vec::new()];
+        for (span_index, span) in self.spans.iter().enumerate() {
+            if span.image_fonts.is_empty() {
+                continue;
+            }
+            for (index, line) in span.filtered_text.filtered_lines().enumerate() {
+                if index > 0 {
+                    lines.push(Vec::new());
+                }
+                if let Some(current_line) = lines.last_mut() {
+                    current_line.extend(line.map(|character| SpanCharacter {
+                        character,
+                        span_index,
+                    }));
+                }
+            }
+        }
+        lines
+    }
+
+    /// Returns the width lines are wrapped at, in the same units as
+    /// [`character_advance`](Self::character_advance).
+    ///
+    /// The text's `max_width` is given in displayed units, so it is converted
+    /// to the font's native size when scaling isn't applied to the glyph
+    /// dimensions.
+    ///
+    /// # Returns
+    /// - `Some(f32)`: The maximum width of a line.
+    /// - `None`: If the text has no `max_width` and shouldn't be wrapped.
+    #[inline]
+    fn wrap_width(&self) -> Option<f32> {
+        let max_width = self.image_font_text.max_width?;
+        if self.render_config.apply_scaling {
+            Some(max_width)
+        } else {
+            Some(max_width / self.scale())
+        }
+    }
+
+    /// Lays out a single line of characters, starting at x-position 0.
+    ///
+    /// The line is left-aligned and not marked as ending a paragraph; both are
+    /// taken care of by the caller.
+    ///
+    /// # Parameters
+    /// - `line_index`: The index of the line being laid out.
+    /// - `characters`: The filtered characters making up the line.
+    ///
+    /// # Returns
+    /// A [`TextLine`] with the placed glyphs and the total width of the line,
+    /// i.e. the sum of the advances of its glyphs and the kerning between
+    /// them.
+    fn layout_line(
+        &self,
+        line_index: usize,
+        characters: impl Iterator<Item = SpanCharacter>,
+    ) -> TextLine {
+        let mut line = TextLine::default();
+        let mut x_pos = 0.;
+        let mut previous_character = None;
+
+        for character in characters {
+            if let Some(previous_character) = previous_character.replace(character) {
+                x_pos += self.kerning(previous_character, character);
+            }
 
-        for character in self.filtered_text.filtered_chars() {
-            let (width, _) = self.character_dimensions(character);
-            text_width += width;
+            line.glyphs.push(PlacedGlyph {
+                character: character.character,
+                span_index: character.span_index,
+                x_pos,
+                line_index,
+            });
+            x_pos += self.character_advance(character);
         }
-        text_width
+        line.width = x_pos;
+
+        line
     }
 
     /// Computes the dimensions of a glyph for a given character, applying
@@ -206,27 +427,94 @@ impl<'assets> RenderContext<'assets> {
         clippy::cast_precision_loss,
         reason = "the magnitude of the numbers we're working on here are too small to lose anything"
     )]
-    pub(crate) fn character_dimensions(&self, character: char) -> (f32, f32) {
-        let image_font_character = &self.image_font.atlas_character_map[&character];
-        let rect = self.atlas_layouts[image_font_character.page_index].textures
-            [image_font_character.character_index];
+    pub(crate) fn character_dimensions(&self, character: SpanCharacter) -> (f32, f32) {
+        let rect = self.character_rect(character);
         let letter_spacing = self.render_config.letter_spacing;
         let width = rect.width() as f32 + letter_spacing;
         let height = rect.height() as f32;
-        let max_height = self.max_height() as f32;
-
-        if let Some(font_height) = self.image_font_text.font_height {
-            if self.render_config.apply_scaling {
-                let scaling_mode = self.render_config.scaling_mode;
-                let scale_factor = font_height / max_height;
-                return (
-                    scaling_mode.apply_scale(width, scale_factor),
-                    scaling_mode.apply_scale(height, scale_factor),
-                );
-            }
+
+        (
+            self.apply_scaling(character.span_index, width),
+            self.apply_scaling(character.span_index, height),
+        )
+    }
+
+    /// Computes the kerning adjustment between two consecutive characters,
+    /// applying scaling if a specific font height is provided and
+    /// `RenderConfig::apply_scaling` is `true`.
+    ///
+    /// Kerning only applies between characters of the same span that are
+    /// rendered with the same font.
+    ///
+    /// # Parameters
+    /// - `left`: The character that comes first.
+    /// - `right`: The character directly following `left`.
+    ///
+    /// # Returns
+    /// The horizontal adjustment to apply to `right` and every following glyph
+    /// on the line, in the same units as
+    /// [`character_dimensions`](Self::character_dimensions).
+    pub(crate) fn kerning(&self, left: SpanCharacter, right: SpanCharacter) -> f32 {
+        let font_index = self.font_index(right);
+        if left.span_index != right.span_index || self.font_index(left) != font_index {
+            return 0.;
         }
+        let kerning = self.spans[right.span_index].image_fonts[font_index]
+            .kerning(left.character, right.character);
+        self.apply_scaling(right.span_index, kerning)
+    }
 
-        (width, height)
+    /// Computes how far the x-position advances after a given character,
+    /// applying scaling if a specific font height is provided and
+    /// `RenderConfig::apply_scaling` is `true`.
+    ///
+    /// This is the character's `x_advance` if the font specifies one, and the
+    /// width of its glyph otherwise. In both cases, the configured letter
+    /// spacing is included.
+    ///
+    /// # Parameters
+    /// - `character`: The character whose advance should be computed.
+    ///
+    /// # Returns
+    /// The advance, in the same units as
+    /// [`character_dimensions`](Self::character_dimensions).
+    pub(crate) fn character_advance(&self, character: SpanCharacter) -> f32 {
+        match self.character_x_advance(character) {
+            Some(x_advance) => self.apply_scaling(
+                character.span_index,
+                x_advance + self.render_config.letter_spacing,
+            ),
+            None => self.character_dimensions(character).0,
+        }
+    }
+
+    /// Scales a distance given at the native height of a span's font.
+    ///
+    /// If `RenderConfig::apply_scaling` is `true`, the distance is scaled to
+    /// the span's font height according to the configured `scaling_mode`, if
+    /// it has one. Otherwise, it is converted to the native height of the
+    /// text's own font, which only changes it for spans with a different font
+    /// or height than the text.
+    ///
+    /// # Parameters
+    /// - `span_index`: The index of the span the distance belongs to.
+    /// - `value`: The distance to scale.
+    ///
+    /// # Returns
+    /// The scaled distance.
+    fn apply_scaling(&self, span_index: usize, value: f32) -> f32 {
+        if !self.render_config.apply_scaling {
+            return value * self.relative_scale(span_index);
+        }
+
+        if self.spans[span_index].font_height.is_some() {
+            return self
+                .render_config
+                .scaling_mode
+                .apply_scale(value, self.span_scale(span_index));
+        }
+
+        value
     }
 
     /// Retrieves the offset for positioning a specific character in the text
@@ -240,11 +528,35 @@ impl<'assets> RenderContext<'assets> {
     /// - `character`: The character whose offset should be retrieved.
     ///
     /// # Returns
-    /// A [`Vec2`] containing the X and Y offsets for the character.
+    /// A [`Vec2`] containing the X and Y offsets for the character, at the
+    /// native height of its font.
     #[inline]
-    pub(crate) fn character_offsets(&self, character: char) -> Vec2 {
-        let image_font_character = &self.image_font.atlas_character_map[&character];
-        image_font_character.offsets
+    fn character_offsets(&self, character: SpanCharacter) -> Vec2 {
+        self.image_font_character(character).offsets
+    }
+
+    /// Computes how far a glyph is moved from its place in the text layout.
+    ///
+    /// This combines the character's offsets from its font with the
+    /// adjustment that aligns the glyphs of spans with a different font or
+    /// height than the text to the bottom of their line. For characters of
+    /// the text's own `text`, this is the same as their offsets.
+    ///
+    /// # Parameters
+    /// - `character`: The character whose glyph should be positioned.
+    ///
+    /// # Returns
+    /// The offsets of the glyph at the native height of the text's font, with
+    /// positive Y values moving it up.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "`max_height` won't ever be particularly large"
+    )]
+    pub(crate) fn glyph_offsets(&self, character: SpanCharacter) -> Vec2 {
+        let relative_scale = self.relative_scale(character.span_index);
+        let span_height = self.reference_height(character.span_index) as f32 * relative_scale;
+        self.character_offsets(character) * relative_scale
+            - Vec2::new(0., self.max_height() as f32 - span_height)
     }
 
     /// Retrieves the horizontal advance for a given character.
@@ -260,9 +572,48 @@ impl<'assets> RenderContext<'assets> {
     /// - `Some(f32)`: If the font specifies an advance width for the character.
     /// - `None`: If no specific advance width is defined.
     #[inline]
-    pub(crate) fn character_x_advance(&self, character: char) -> Option<f32> {
-        let image_font_character = &self.image_font.atlas_character_map[&character];
-        image_font_character.x_advance
+    pub(crate) fn character_x_advance(&self, character: SpanCharacter) -> Option<f32> {
+        self.image_font_character(character).x_advance
+    }
+
+    /// Determines which of the fonts a character is rendered with.
+    ///
+    /// # Parameters
+    /// - `character`: A character of the filtered text.
+    ///
+    /// # Returns
+    /// The index of the first font of the character's span containing it:
+    /// `0` for the span's font, and `n` for the text's `n`th loaded fallback
+    /// font. Characters that none of the fonts contain are attributed to the
+    /// span's font.
+    #[inline]
+    pub(crate) fn font_index(&self, character: SpanCharacter) -> usize {
+        self.spans[character.span_index].font_index(character.character)
+    }
+
+    /// Retrieves the font a character is rendered with.
+    ///
+    /// # Parameters
+    /// - `character`: A character of the filtered text.
+    ///
+    /// # Returns
+    /// The first of the fonts of the character's span that contains it.
+    #[inline]
+    pub(crate) fn character_font(&self, character: SpanCharacter) -> &'assets ImageFont {
+        self.spans[character.span_index].character_font(character.character)
+    }
+
+    /// Retrieves the glyph metadata of a character from the font it is
+    /// rendered with.
+    #[inline]
+    fn image_font_character(&self, character: SpanCharacter) -> &'assets ImageFontCharacter {
+        self.spans[character.span_index].image_font_character(character.character)
+    }
+
+    /// Retrieves the rectangle of a character's glyph within its texture page.
+    #[inline]
+    fn character_rect(&self, character: SpanCharacter) -> URect {
+        self.spans[character.span_index].character_rect(character.character)
     }
 
     /// Retrieves the handle to the font texture image.
@@ -270,10 +621,10 @@ impl<'assets> RenderContext<'assets> {
     /// This handle is used to assign the appropriate image to a text sprite.
     #[inline]
     #[cfg(feature = "atlas_sprites")]
-    pub(crate) fn font_image(&self, character: char) -> Handle<Image> {
-        let image_font_character = &self.image_font.atlas_character_map[&character];
+    pub(crate) fn font_image(&self, character: SpanCharacter) -> Handle<Image> {
+        let image_font_character = self.image_font_character(character);
 
-        self.image_font.textures[image_font_character.page_index].clone_weak()
+        self.character_font(character).textures[image_font_character.page_index].clone_weak()
     }
 
     /// Constructs the texture atlas entry for a specific character.
@@ -287,47 +638,76 @@ impl<'assets> RenderContext<'assets> {
     /// # Returns
     /// A [`TextureAtlas`] structure containing the layout and character index.
     #[inline]
-    pub(crate) fn font_texture_atlas(&self, character: char) -> TextureAtlas {
-        let image_font_character = &self.image_font.atlas_character_map[&character];
+    pub(crate) fn font_texture_atlas(&self, character: SpanCharacter) -> TextureAtlas {
+        let image_font_character = self.image_font_character(character);
         TextureAtlas {
-            layout: self.image_font.atlas_layouts[image_font_character.page_index].clone_weak(),
+            layout: self.character_font(character).atlas_layouts[image_font_character.page_index]
+                .clone_weak(),
             index: image_font_character.character_index,
         }
     }
 
-    /// Returns the filtered text, which includes only characters supported by
-    /// the font.
+    /// Returns the color the glyphs of a span are tinted with.
+    ///
+    /// # Parameters
+    /// - `span_index`: The index of the span.
+    ///
+    /// # Returns
+    /// The span's own color if it has one, and the configured text color
+    /// otherwise.
+    #[inline]
+    pub(crate) fn glyph_color(&self, span_index: usize) -> Color {
+        self.spans[span_index]
+            .color
+            .unwrap_or(self.render_config.color)
+    }
+
+    /// Returns an iterator over the filtered characters of all spans of the
+    /// text, which only include characters supported by their fonts.
     ///
-    /// The filtered text excludes unsupported or invalid characters, ensuring
-    /// that only renderable glyphs are processed.
+    /// Unsupported or invalid characters are excluded, ensuring that only
+    /// renderable glyphs are processed. Line breaks aren't included either.
+    #[inline]
+    #[cfg(any(feature = "rendered", test))]
+    pub(crate) fn filtered_chars(&self) -> impl Iterator<Item = SpanCharacter> + '_ {
+        self.spans
+            .iter()
+            .enumerate()
+            .flat_map(|(span_index, span)| {
+                span.filtered_text
+                    .filtered_chars()
+                    .map(move |character| SpanCharacter {
+                        character,
+                        span_index,
+                    })
+            })
+    }
+
+    /// Checks whether the text has no characters to render.
     #[inline]
-    pub(crate) fn text(&self) -> &FilteredString<'_, &String> {
-        &self.filtered_text
+    #[cfg(feature = "rendered")]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.filtered_chars().next().is_none()
     }
 
-    /// Updates the texture index for the specified character and assigns the
+    /// Points a sprite at the glyph of the specified character and assigns the
     /// configured color.
     ///
-    /// This function assigns the correct glyph index from the font atlas to
-    /// `texture_atlas`, ensuring that the correct character is selected for
-    /// rendering. Additionally, it assigns the configured text color from
-    /// `RenderConfig` to `color`.
+    /// This function assigns the texture page and texture atlas entry of the
+    /// character's glyph to `sprite`, ensuring that the correct character is
+    /// selected for rendering even if it lives on a different page or in a
+    /// different font than the sprite's previous character. Additionally, it
+    /// assigns the color of the character's span to the sprite.
     ///
     /// # Parameters
     /// - `character`: The character whose corresponding glyph should be used.
-    /// - `texture_atlas`: The texture atlas entry that will be updated with the
-    ///   character's index.
-    /// - `color`: The variable that will be assigned the value of
-    ///   `RenderConfig::color`.
+    /// - `sprite`: The sprite that will be updated.
     #[inline]
-    pub(crate) fn update_render_values(
-        &self,
-        character: char,
-        texture_atlas: &mut TextureAtlas,
-        color: &mut Color,
-    ) {
-        texture_atlas.index = self.image_font.atlas_character_map[&character].character_index;
-        *color = self.render_config.color;
+    #[cfg(feature = "atlas_sprites")]
+    pub(crate) fn update_render_values(&self, character: SpanCharacter, sprite: &mut Sprite) {
+        sprite.image = self.font_image(character);
+        sprite.texture_atlas = Some(self.font_texture_atlas(character));
+        sprite.color = self.glyph_color(character.span_index);
     }
 
     /// Computes or retrieves the cached anchor offsets for the text and glyph
@@ -343,6 +723,7 @@ impl<'assets> RenderContext<'assets> {
     /// - `whole`: Offset for aligning the entire text block.
     /// - `individual`: Offset for aligning each individual glyph.
     #[inline]
+    #[cfg(feature = "atlas_sprites")]
     pub(crate) fn anchor_offsets(&self) -> AnchorOffsets {
         self.render_config
             .text_anchor
@@ -352,42 +733,221 @@ impl<'assets> RenderContext<'assets> {
     /// Computes the transform for positioning and scaling a text sprite.
     ///
     /// This method calculates the sprite's translation and scale based on:
-    /// - The x-position of the sprite.
+    /// - The position of the glyph within the text layout.
     /// - The dimensions of the character's glyph.
     /// - The alignment offsets and scaling configuration.
     ///
     /// # Parameters
-    /// - `x_pos`: A mutable reference to the current x-position of the sprite.
-    ///   This value is updated to reflect the position of the next sprite.
-    /// - `character`: The character associated with the sprite.
+    /// - `glyph`: The placed glyph associated with the sprite, as found in
+    ///   [`layout`](Self::layout).
     ///
     /// # Returns
     /// A [`Transform`] representing the position and scale of the sprite.
     #[inline]
-    pub(crate) fn transform(&self, x_pos: &mut f32, character: char) -> Transform {
-        let x = *x_pos;
+    #[cfg(feature = "atlas_sprites")]
+    pub(crate) fn transform(&self, glyph: &PlacedGlyph) -> Transform {
+        let character = glyph.span_character();
         let (width, height) = self.character_dimensions(character);
-        *x_pos += self.character_x_advance(character).unwrap_or(width);
 
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "the line count won't ever be particularly large"
+        )]
         let params = ComputeTransformParams {
-            x_pos: x,
+            x_pos: glyph.x_pos,
             scaled_text_width: self.text_width(),
             scaled_width: width,
             scaled_height: height,
             max_height: self.max_height(),
-            character_offsets: self.character_offsets(character),
+            text_height: self.text_height(),
+            line_offset: glyph.line_index as f32 * self.line_height(),
+            character_offsets: self.glyph_offsets(character),
             scale: self.scale(),
+            glyph_scale: self.span_scale(character.span_index),
         };
         self.anchor_offsets().compute_transform(params)
     }
 }
 
+/// The assets and style a single span of a text is rendered with.
+///
+/// The text's own `text` is represented by a `SpanContext` as well, which is
+/// always the first one in [`RenderContext::spans`].
+struct SpanContext<'assets> {
+    /// The texture atlas layouts defining glyph placements, one list of pages
+    /// per entry in `image_fonts`.
+    atlas_layouts: Vec<Vec<&'assets TextureAtlasLayout>>,
+    /// The font assets containing glyph metadata: the span's font, followed by
+    /// the text's loaded fallback fonts in order of preference. Each character
+    /// is rendered using the first of these fonts that contains it. Empty if
+    /// the span's font isn't loaded.
+    image_fonts: Vec<&'assets ImageFont>,
+    /// The span's text filtered to include only supported characters in the
+    /// font atlases.
+    filtered_text: FilteredString<'assets, &'assets String>,
+    /// Whether the span is rendered with the text's own font, in which case it
+    /// is scaled relative to the text's [`RenderContext::max_height`].
+    uses_text_font: bool,
+    /// The height the span is rendered at, if any.
+    font_height: Option<f32>,
+    /// The color the span is tinted with instead of the configured text
+    /// color, if any.
+    color: Option<Color>,
+    /// Cached native height the span is scaled relative to, for spans that
+    /// don't use the text's own font.
+    reference_height: CacheCell<u32>,
+}
+
+impl<'assets> SpanContext<'assets> {
+    /// Creates a new `SpanContext`, assuming that it uses the text's own font.
+    ///
+    /// # Parameters
+    /// - `image_fonts`: The fonts to render the span with, in order of
+    ///   preference.
+    /// - `text`: The span's text.
+    /// - `font_height`: The height to render the span at, if any.
+    /// - `color`: The color to tint the span with, if it has its own.
+    /// - `texture_atlas_layouts`: The asset collection containing texture atlas
+    ///   layouts.
+    ///
+    /// # Returns
+    /// - `Some(SpanContext)`: If the texture atlas layouts of all fonts are
+    ///   loaded.
+    /// - `None`: If any of them are missing.
+    fn new(
+        image_fonts: Vec<&'assets ImageFont>,
+        text: &'assets String,
+        font_height: Option<f32>,
+        color: Option<Color>,
+        texture_atlas_layouts: &'assets Assets<TextureAtlasLayout>,
+    ) -> Option<Self> {
+        let atlas_layouts: Result<Vec<Vec<_>>, _> = image_fonts
+            .iter()
+            .map(|image_font| {
+                image_font
+                    .atlas_layouts
+                    .iter()
+                    .map(|texture_atlas_layout| {
+                        texture_atlas_layouts
+                            .get(texture_atlas_layout)
+                            .ok_or_else(|| {
+                                format!("TextureAtlasLayout not loaded: {texture_atlas_layout:?}")
+                            })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let atlas_layouts = match atlas_layouts {
+            Ok(layout) => layout,
+            Err(error) => {
+                error!("{error}");
+                return None;
+            }
+        };
+
+        let filtered_text = FilteredString::new(
+            text,
+            image_fonts
+                .iter()
+                .map(|image_font| &image_font.atlas_character_map),
+            image_fonts
+                .first()
+                .and_then(|image_font| image_font.fallback_character),
+        );
+
+        Some(Self {
+            atlas_layouts,
+            image_fonts,
+            filtered_text,
+            uses_text_font: true,
+            font_height,
+            color,
+            reference_height: default(),
+        })
+    }
+
+    /// Calculates the height of a line of this span at its font's native
+    /// height.
+    ///
+    /// This is the line height of the span's font if it has
+    /// [`line_metrics`](ImageFont::line_metrics), and the height of the
+    /// tallest glyph that `font_height_reference` refers to otherwise.
+    ///
+    /// # Returns
+    /// The height, which is at least `1`.
+    fn native_height(&self, font_height_reference: FontHeightReference) -> u32 {
+        let Some(image_font) = self.image_fonts.first() else {
+            return 1;
+        };
+        if let Some(line_metrics) = image_font.line_metrics {
+            return line_metrics.line_height.max(1);
+        }
+
+        match font_height_reference {
+            FontHeightReference::TallestGlyphInFont => image_font
+                .atlas_character_map
+                .values()
+                .map(|image_font_character| {
+                    self.atlas_layouts[0][image_font_character.page_index].textures
+                        [image_font_character.character_index]
+                        .height()
+                })
+                .fold(1, u32::max),
+            FontHeightReference::TallestGlyphInText => self
+                .filtered_text
+                .filtered_chars()
+                .map(|character| self.character_rect(character).height())
+                .fold(1, u32::max),
+        }
+    }
+
+    /// Determines which of the span's fonts a character is rendered with.
+    ///
+    /// # Returns
+    /// The index of the first font containing `character`, or `0` if none of
+    /// them do.
+    #[inline]
+    fn font_index(&self, character: char) -> usize {
+        self.image_fonts
+            .iter()
+            .position(|image_font| image_font.atlas_character_map.contains_key(&character))
+            .unwrap_or_default()
+    }
+
+    /// Retrieves the first of the span's fonts that contains a character.
+    #[inline]
+    fn character_font(&self, character: char) -> &'assets ImageFont {
+        self.image_fonts[self.font_index(character)]
+    }
+
+    /// Retrieves the glyph metadata of a character from the font it is
+    /// rendered with.
+    #[inline]
+    fn image_font_character(&self, character: char) -> &'assets ImageFontCharacter {
+        &self.character_font(character).atlas_character_map[&character]
+    }
+
+    /// Retrieves the rectangle of a character's glyph within its texture page.
+    #[inline]
+    fn character_rect(&self, character: char) -> URect {
+        let font_index = self.font_index(character);
+        let image_font_character = self.image_font_character(character);
+        self.atlas_layouts[font_index][image_font_character.page_index].textures
+            [image_font_character.character_index]
+    }
+}
+
 /// Configuration settings for rendering text using an `ImageFont`.
 ///
 /// This struct controls how text is rendered, including alignment, spacing,
 /// scaling, and color settings. It is passed to `RenderContext` to determine
 /// rendering behavior.
 #[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(
+    not(feature = "atlas_sprites"),
+    expect(dead_code, reason = "anchoring is only used by `atlas_sprites`")
+)]
 pub(crate) struct RenderConfig {
     /// The anchor point used to align the rendered text.
     ///
@@ -429,7 +989,7 @@ pub(crate) struct RenderConfig {
     /// The color applied to the rendered text.
     ///
     /// This affects all glyphs uniformly, allowing text to be tinted or styled
-    /// dynamically.
+    /// dynamically, except for those of spans with a color of their own.
     pub color: Color,
 }
 
@@ -87,8 +87,11 @@ impl AnchorOffsets {
             scaled_width,
             scaled_height,
             max_height,
+            text_height,
+            line_offset,
             character_offsets,
             scale,
+            glyph_scale,
         } = params;
 
         // Step 1: Start with the base x_pos translation
@@ -117,11 +120,21 @@ impl AnchorOffsets {
             translation += Vec2::new(0.0, max_height as f32 - height) * scale * 0.5;
         }
 
-        // Step 5: Apply character offsets
+        // Step 5: account for multiple lines
+        apply_line_offset(
+            &mut translation,
+            max_height,
+            text_height,
+            line_offset,
+            scale,
+            self.whole,
+        );
+
+        // Step 6: Apply character offsets
         translation += character_offsets * scale;
 
-        // Step 6: Finalize the transform
-        finalize_transform(translation, scale)
+        // Step 7: Finalize the transform
+        finalize_transform(translation, glyph_scale)
     }
 }
 
@@ -139,12 +152,22 @@ pub(crate) struct ComputeTransformParams {
     pub scaled_width: f32,
     /// The height of the current glyph.
     pub scaled_height: f32,
-    /// The maximum height of the text block.
+    /// The maximum height of a glyph in the text block.
     pub max_height: u32,
-    /// The per-character offsets applied to the glyph.
+    /// The height of the whole text block, including all lines.
+    pub text_height: f32,
+    /// The distance from the top of the first line to the top of the glyph's
+    /// line.
+    pub line_offset: f32,
+    /// The per-character offsets applied to the glyph, at the native height
+    /// of the text's font.
     pub character_offsets: Vec2,
-    /// The uniform scaling factor applied to the glyph.
+    /// The uniform scaling factor applied to the text block.
     pub scale: f32,
+    /// The scaling factor applied to the glyph itself. This is the same as
+    /// `scale`, unless the glyph belongs to a span with a different font or
+    /// height than the text.
+    pub glyph_scale: f32,
 }
 
 /// Creates the initial base translation for a sprite.
@@ -200,6 +223,39 @@ fn apply_individual_offset(translation: &mut Vec2, width: f32, individual: Vec2)
     *translation += Vec2::new(width * individual.x, 0.0);
 }
 
+/// Adjusts the translation to place the glyph on its line in a multi-line text
+/// block.
+///
+/// The whole offset only accounts for a single line of height `max_height`;
+/// this shifts the glyph by the additional height of the block, according to
+/// the vertical anchor, and moves it down to its own line.
+///
+/// # Parameters
+/// - `translation`: A mutable reference to the translation vector to modify.
+/// - `max_height`: Maximum height of a glyph in the text block.
+/// - `text_height`: Height of the whole text block.
+/// - `line_offset`: Distance from the top of the block to the glyph's line.
+/// - `scale`: Scaling factor for glyph dimensions.
+/// - `whole`: The `whole` offset vector for aligning the entire text block.
+///
+/// # Side Effects
+/// Modifies the `translation` vector; for single-line text it is unchanged.
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "we're working on numbers small enough not to be affected"
+)]
+fn apply_line_offset(
+    translation: &mut Vec2,
+    max_height: u32,
+    text_height: f32,
+    line_offset: f32,
+    scale: f32,
+    whole: Vec2,
+) {
+    let extra_height = text_height - max_height as f32;
+    translation.y += (extra_height * (whole.y + 0.5) - line_offset) * scale;
+}
+
 /// Converts a `Vec2` translation into a `Transform` with scaling.
 ///
 /// # Parameters
@@ -279,6 +335,28 @@ mod tests {
         assert_eq!(translation, Vec2::new(20.0, 0.0));
     }
 
+    #[test]
+    fn apply_line_offset_applies_the_correct_offset() {
+        // Single line: the text block is exactly one glyph high, so nothing changes
+        let mut translation = Vec2::new(10.0, 5.0);
+        apply_line_offset(&mut translation, 10, 10.0, 0.0, 2.0, Vec2::new(0.0, -0.5));
+        assert_eq!(translation, Vec2::new(10.0, 5.0));
+
+        // Three lines of height 10, top anchored (whole.y = -0.5): the first line
+        // stays put and the third line moves down by two line heights, scaled.
+        let mut translation = Vec2::ZERO;
+        apply_line_offset(&mut translation, 10, 30.0, 0.0, 2.0, Vec2::new(0.0, -0.5));
+        assert_eq!(translation, Vec2::ZERO);
+        apply_line_offset(&mut translation, 10, 30.0, 20.0, 2.0, Vec2::new(0.0, -0.5));
+        assert_eq!(translation, Vec2::new(0.0, -40.0));
+
+        // Bottom anchored (whole.y = 0.5): the first line moves up by the extra
+        // height of the block.
+        let mut translation = Vec2::ZERO;
+        apply_line_offset(&mut translation, 10, 30.0, 0.0, 1.0, Vec2::new(0.0, 0.5));
+        assert_eq!(translation, Vec2::new(0.0, 20.0));
+    }
+
     #[test]
     fn finalize_transform_produces_expected_transform() {
         // Case 1: Normal translation and scale
@@ -312,8 +390,11 @@ mod tests {
             scaled_width: 5.0,
             scaled_height: 20.,
             max_height: 30,
+            text_height: 30.,
+            line_offset: 0.,
             character_offsets: Vec2::ZERO,
             scale: 1.5,
+            glyph_scale: 1.5,
         };
         let transform = offsets.compute_transform(params);
         assert_eq!(transform.translation, Vec3::new(32.5, -32.5, 0.0));
@@ -326,8 +407,11 @@ mod tests {
             scaled_width: 2.0,
             scaled_height: 5.0,
             max_height: 50,
+            text_height: 50.,
+            line_offset: 0.,
             character_offsets: Vec2::ZERO,
             scale: 0.0,
+            glyph_scale: 0.0,
         };
         let transform = offsets.compute_transform(params);
         assert_eq!(transform.translation, Vec3::new(11.0, 0.0, 0.0));
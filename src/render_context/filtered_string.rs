@@ -8,7 +8,8 @@
 //! and efficient abstraction for working with strings filtered according to a
 //! predefined character map. This is particularly useful in scenarios where
 //! only a subset of characters are supported, such as rendering text with an
-//! `ImageFont`.
+//! `ImageFont`. Unsupported characters can optionally be replaced by a fallback
+//! character rather than being dropped.
 //!
 //! # Key Features
 //! - **Efficient Filtering:** Filters strings without unnecessary allocations,
@@ -30,28 +31,36 @@ use bevy::platform::collections::HashMap;
 
 use crate::{ImageFont, ImageFontCharacter};
 
-/// A wrapper type for filtering characters from a string based on a character
-/// map.
+/// A wrapper type for filtering characters from a string based on character
+/// maps.
 ///
 /// This type allows you to filter out characters from a string that do not
-/// exist in the given `atlas_character_map`. It provides a reusable abstraction
-/// for working with filtered strings efficiently, avoiding unnecessary
-/// allocations.
+/// exist in any of the given `atlas_character_maps`. It provides a reusable
+/// abstraction for working with filtered strings efficiently, avoiding
+/// unnecessary allocations.
 #[derive(Debug)]
 pub(crate) struct FilteredString<'map, S: AsRef<str>> {
     /// The input string to be filtered.
     ///
     /// This string serves as the source for filtering operations. Characters
     /// in this string are compared against the characters in
-    /// `atlas_character_map`.
+    /// `atlas_character_maps`.
     string: S,
 
-    /// A reference to a map of characters to their indices in a texture atlas.
+    /// References to maps of characters to their indices in a texture atlas,
+    /// one for each font the string may be rendered with.
     ///
-    /// This map determines which characters from `string` are retained during
-    /// filtering. Only characters present as keys in this map will be included
-    /// in the filtered output.
-    atlas_character_map: &'map HashMap<char, ImageFontCharacter>,
+    /// These maps determine which characters from `string` are retained
+    /// during filtering. Only characters present as keys in at least one of
+    /// these maps will be included in the filtered output.
+    atlas_character_maps: Vec<&'map HashMap<char, ImageFontCharacter>>,
+
+    /// The character that replaces characters missing from all of the
+    /// `atlas_character_maps`.
+    ///
+    /// Only used if it is present in one of the `atlas_character_maps` itself;
+    /// otherwise, missing characters are dropped.
+    fallback_character: Option<char>,
 }
 
 impl<'map, S: AsRef<str>> FilteredString<'map, S> {
@@ -59,34 +68,85 @@ impl<'map, S: AsRef<str>> FilteredString<'map, S> {
     ///
     /// # Parameters
     /// - `string`: The input string to be filtered.
-    /// - `atlas_character_map`: A reference to a character map that determines
-    ///   which characters are retained.
+    /// - `atlas_character_maps`: References to the character maps that
+    ///   determine which characters are retained.
+    /// - `fallback_character`: The character to substitute for characters that
+    ///   aren't in any of the `atlas_character_maps`, if any.
     ///
     /// # Returns
     /// A `FilteredString` instance that can produce iterators over the filtered
     /// characters.
     pub(crate) fn new(
         string: S,
-        atlas_character_map: &'map HashMap<char, ImageFontCharacter>,
+        atlas_character_maps: impl IntoIterator<Item = &'map HashMap<char, ImageFontCharacter>>,
+        fallback_character: Option<char>,
     ) -> Self {
-        Self {
+        let mut filtered_string = Self {
             string,
-            atlas_character_map,
-        }
+            atlas_character_maps: atlas_character_maps.into_iter().collect(),
+            fallback_character: None,
+        };
+        filtered_string.fallback_character =
+            fallback_character.filter(|&fallback| filtered_string.contains(fallback));
+        filtered_string
     }
 
     /// Returns an iterator over the filtered characters.
     ///
     /// This method filters the input string to include only characters that
-    /// exist in the `atlas_character_map`.
+    /// exist in one of the `atlas_character_maps`, replacing the others with
+    /// the fallback character if there is one.
     ///
     /// # Returns
     /// An iterator that yields characters retained by the filter.
     pub(crate) fn filtered_chars(&self) -> impl Iterator<Item = char> + '_ {
-        self.string
-            .as_ref()
-            .chars()
-            .filter(|character| self.atlas_character_map.contains_key(character))
+        self.filtered_lines().flatten()
+    }
+
+    /// Returns an iterator over the lines of the input string, each line being
+    /// an iterator over its filtered characters.
+    ///
+    /// Lines are separated by `\n`; a `\r` directly preceding it is dropped so
+    /// that `\r\n` line endings behave the same. Unlike [`str::lines`], a
+    /// trailing newline produces a trailing empty line, and an empty string
+    /// produces a single empty line. Characters missing from the character map
+    /// are replaced or dropped as in
+    /// [`filtered_chars`](Self::filtered_chars).
+    ///
+    /// # Returns
+    /// An iterator yielding one iterator of retained characters per line.
+    pub(crate) fn filtered_lines(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = char> + '_> + '_ {
+        self.string.as_ref().split('\n').map(|line| {
+            line.strip_suffix('\r')
+                .unwrap_or(line)
+                .chars()
+                .filter_map(|character| self.filter_char(character))
+        })
+    }
+
+    /// Determines what a single character of the input string turns into.
+    ///
+    /// # Returns
+    /// - `Some(character)`: If `character` is in the character map.
+    /// - `Some(fallback)`: If it isn't, but there is a fallback character.
+    /// - `None`: If the character should be dropped.
+    #[inline]
+    fn filter_char(&self, character: char) -> Option<char> {
+        if self.contains(character) {
+            Some(character)
+        } else {
+            self.fallback_character
+        }
+    }
+
+    /// Checks whether any of the character maps contains a character.
+    #[inline]
+    fn contains(&self, character: char) -> bool {
+        self.atlas_character_maps
+            .iter()
+            .any(|atlas_character_map| atlas_character_map.contains_key(&character))
     }
 
     /// Checks if the filtered string is empty.
@@ -117,7 +177,8 @@ impl ImageFont {
     /// [`FilteredString`](filtered_string::FilteredString) containing only the
     /// characters from the input string that exist in the font's
     /// `atlas_character_map`. It ensures that unsupported characters are
-    /// excluded during rendering.
+    /// excluded during rendering, or replaced by the font's
+    /// `fallback_character` if it has one.
     ///
     /// # Parameters
     /// - `string`: The input string to filter.
@@ -129,7 +190,7 @@ impl ImageFont {
     /// This function requires either the `rendered` or `atlas_sprites` feature
     /// to be enabled.
     pub(super) fn filter_string<S: AsRef<str>>(&self, string: S) -> FilteredString<'_, S> {
-        FilteredString::new(string, &self.atlas_character_map)
+        FilteredString::new(string, [&self.atlas_character_map], self.fallback_character)
     }
 }
 
@@ -23,7 +23,7 @@ fn filters_chars() {
         },
     );
 
-    let filtered_string = FilteredString::new("abcd", &atlas_character_map);
+    let filtered_string = FilteredString::new("abcd", [&atlas_character_map], None);
     let filtered_chars: Vec<_> = filtered_string.filtered_chars().collect();
 
     assert_eq!(filtered_chars, vec!['a', 'b']);
@@ -49,7 +49,7 @@ fn is_empty_when_no_characters_retained() {
         },
     );
 
-    let filtered_string = FilteredString::new("abc", &atlas_character_map);
+    let filtered_string = FilteredString::new("abc", [&atlas_character_map], None);
 
     assert!(filtered_string.is_empty());
 }
@@ -66,11 +66,65 @@ fn is_not_empty_when_characters_retained() {
         },
     );
 
-    let filtered_string = FilteredString::new("abc", &atlas_character_map);
+    let filtered_string = FilteredString::new("abc", [&atlas_character_map], None);
 
     assert!(!filtered_string.is_empty());
 }
 
+#[test]
+fn filters_lines() {
+    let mut atlas_character_map = HashMap::new();
+    atlas_character_map.insert(
+        'a',
+        ImageFontCharacter {
+            page_index: 0,
+            character_index: 1,
+            ..default()
+        },
+    );
+
+    let filtered_string = FilteredString::new("ab\nba\r\n\nc", [&atlas_character_map], None);
+    let filtered_lines: Vec<String> = filtered_string
+        .filtered_lines()
+        .map(Iterator::collect)
+        .collect();
+
+    assert_eq!(filtered_lines, vec!["a", "a", "", ""]);
+    assert_eq!(filtered_string.to_string(), "aa");
+}
+
+#[test]
+fn replaces_missing_chars_with_fallback() {
+    let mut atlas_character_map = HashMap::new();
+    atlas_character_map.insert(
+        'a',
+        ImageFontCharacter {
+            page_index: 0,
+            character_index: 1,
+            ..default()
+        },
+    );
+    atlas_character_map.insert(
+        '?',
+        ImageFontCharacter {
+            page_index: 0,
+            character_index: 2,
+            ..default()
+        },
+    );
+
+    let filtered_string = FilteredString::new("abc\nba", [&atlas_character_map], Some('?'));
+    let filtered_lines: Vec<String> = filtered_string
+        .filtered_lines()
+        .map(Iterator::collect)
+        .collect();
+    assert_eq!(filtered_lines, vec!["a??", "?a"]);
+
+    // A fallback character that isn't in the font itself is ignored
+    let filtered_string = FilteredString::new("abc", [&atlas_character_map], Some('!'));
+    assert_eq!(filtered_string.to_string(), "a");
+}
+
 #[test]
 fn display_shows_filtered_text() {
     let mut atlas_character_map = HashMap::new();
@@ -91,7 +145,7 @@ fn display_shows_filtered_text() {
         },
     );
 
-    let filtered_string = FilteredString::new("abcd", &atlas_character_map);
+    let filtered_string = FilteredString::new("abcd", [&atlas_character_map], None);
 
     assert_eq!(filtered_string.to_string(), "ab");
 }
@@ -126,6 +180,9 @@ fn test_image_font_filter_string() {
         textures: vec![Handle::default()],
         atlas_character_map: atlas_character_map.clone(),
         image_sampler: ImageSampler::nearest(),
+        kerning_pairs: HashMap::new(),
+        fallback_character: None,
+        line_metrics: None,
     };
 
     let input = "ABC";
@@ -1,4 +1,3 @@
-use bevy::color::palettes::css;
 use float_eq::assert_float_eq;
 
 use super::*;
@@ -7,6 +6,7 @@ use crate::tests::utils::{
     MONOSPACE_FONT_HEIGHT, MONOSPACE_FONT_WIDTH, VARIABLE_WIDTH_FONT_CHARACTER_WIDTHS,
     VARIABLE_WIDTH_FONT_HEIGHT,
 };
+use crate::{ImageFontLineMetrics, ImageFontSpan, LineAlignment};
 
 #[test]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
@@ -142,8 +142,7 @@ fn text_width() {
         // Verify the total width is computed correctly
         assert_float_eq!(
             width,
-            render_context.filtered_text.filtered_chars().count() as f32
-                * MONOSPACE_FONT_WIDTH as f32,
+            render_context.filtered_chars().count() as f32 * MONOSPACE_FONT_WIDTH as f32,
             abs <= COMPARISON_TOLERANCE
         );
     });
@@ -161,7 +160,7 @@ fn character_dimensions() {
     let render_context_tester = RenderContextTester::new(&app, handle);
 
     render_context_tester.test_with_defaults(|render_context| {
-        let (width, height) = render_context.character_dimensions('A');
+        let (width, height) = render_context.character_dimensions('A'.into());
 
         // Verify width and height are valid
         assert_float_eq!(
@@ -177,6 +176,81 @@ fn character_dimensions() {
     });
 }
 
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn font_height_reference_determines_scale() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    {
+        // Make the glyph of '.' half as tall as the others
+        let world = app.world_mut();
+        let image_font = world.resource::<Assets<ImageFont>>().get(&handle).unwrap();
+        let layout_handle = image_font.atlas_layouts[0].clone_weak();
+        let index = image_font.atlas_character_map[&'.'].character_index;
+        let mut layouts = world.resource_mut::<Assets<TextureAtlasLayout>>();
+        layouts.get_mut(&layout_handle).unwrap().textures[index]
+            .max
+            .y -= MONOSPACE_FONT_HEIGHT / 2;
+    }
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    for (font_height_reference, expected_max_height) in [
+        (
+            FontHeightReference::TallestGlyphInText,
+            MONOSPACE_FONT_HEIGHT / 2,
+        ),
+        (
+            FontHeightReference::TallestGlyphInFont,
+            MONOSPACE_FONT_HEIGHT,
+        ),
+    ] {
+        render_context_tester.modify_and_then_test_with(
+            |tester| {
+                tester.image_font_text.text = String::from("...");
+                tester.image_font_text.font_height_reference = font_height_reference;
+            },
+            |render_context| {
+                let render_context = render_context.unwrap();
+                assert_eq!(
+                    render_context.max_height(),
+                    expected_max_height,
+                    "{font_height_reference:?}"
+                );
+            },
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn max_height_uses_font_line_metrics() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    app.world_mut()
+        .resource_mut::<Assets<ImageFont>>()
+        .get_mut(&handle)
+        .unwrap()
+        .line_metrics = Some(ImageFontLineMetrics::new(16, 10));
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    for text in ["Test", "", "..."] {
+        render_context_tester.modify_and_then_test_with(
+            |tester| {
+                tester.image_font_text.text = String::from(text);
+                tester.image_font_text.font_height = Some(32.0);
+            },
+            |render_context| {
+                let render_context = render_context.unwrap();
+                assert_eq!(render_context.max_height(), 16, "{text:?}");
+                assert_float_eq!(render_context.scale(), 2.0, abs <= COMPARISON_TOLERANCE);
+                assert_float_eq!(
+                    render_context.text_height(),
+                    16.0,
+                    abs <= COMPARISON_TOLERANCE
+                );
+            },
+        );
+    }
+}
+
 #[test]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
 #[expect(
@@ -205,11 +279,11 @@ fn test_mixed_character_widths() {
             );
 
             // Verify individual dimensions
-            for character in render_context.text().filtered_chars() {
+            for character in render_context.filtered_chars() {
                 let (width, height) = render_context.character_dimensions(character);
                 assert_float_eq!(
                     width,
-                    VARIABLE_WIDTH_FONT_CHARACTER_WIDTHS[&character] as f32,
+                    VARIABLE_WIDTH_FONT_CHARACTER_WIDTHS[&character.character] as f32,
                     abs <= COMPARISON_TOLERANCE
                 );
                 assert_float_eq!(
@@ -223,6 +297,7 @@ fn test_mixed_character_widths() {
 }
 
 #[test]
+#[cfg(feature = "atlas_sprites")]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
 fn anchor_offsets() {
     let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
@@ -243,6 +318,7 @@ fn anchor_offsets() {
 }
 
 #[test]
+#[cfg(feature = "atlas_sprites")]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
 #[expect(
     clippy::cast_precision_loss,
@@ -254,19 +330,16 @@ fn transform() {
     let render_context_tester = RenderContextTester::new(&app, handle);
 
     render_context_tester.test_with_defaults(|render_context| {
-        let mut x_pos = 0.0;
-        let transform = render_context.transform(
-            &mut x_pos,
-            render_context.text().filtered_chars().next().unwrap(),
-        );
+        let mut glyphs = render_context.layout().glyphs();
+        let transform = render_context.transform(glyphs.next().unwrap());
 
         // Verify the transform is calculated correctly
         assert_float_eq!(transform.translation.x, -7.5, abs <= COMPARISON_TOLERANCE);
         assert_float_eq!(transform.scale.x, 1.0, abs <= COMPARISON_TOLERANCE);
 
-        // Verify x_pos is updated
+        // Verify the next glyph is placed after the first one
         assert_float_eq!(
-            x_pos,
+            glyphs.next().unwrap().x_pos,
             MONOSPACE_FONT_WIDTH as f32,
             abs <= COMPARISON_TOLERANCE
         );
@@ -274,33 +347,144 @@ fn transform() {
 }
 
 #[test]
+#[cfg(feature = "atlas_sprites")]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn multiple_lines() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AB\r\nC\n");
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let layout = render_context.layout();
+
+            // The trailing newline starts a third, empty line
+            assert_eq!(layout.lines.len(), 3);
+            assert_eq!(layout.glyphs().count(), 3);
+
+            let glyph_c = layout.lines[1].glyphs[0];
+            assert_eq!(glyph_c.character, 'C');
+            assert_eq!(glyph_c.line_index, 1);
+            assert_float_eq!(glyph_c.x_pos, 0.0, abs <= COMPARISON_TOLERANCE);
+
+            // The widest line determines the width
+            assert_float_eq!(
+                render_context.text_width(),
+                2. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_float_eq!(
+                render_context.text_height(),
+                3. * MONOSPACE_FONT_HEIGHT as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+
+            // Each line is one line height below the previous one
+            let glyph_a = layout.lines[0].glyphs[0];
+            let transform_a = render_context.transform(&glyph_a);
+            let transform_c = render_context.transform(&glyph_c);
+            assert_float_eq!(
+                transform_a.translation.y - transform_c.translation.y,
+                MONOSPACE_FONT_HEIGHT as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_float_eq!(
+                transform_a.translation.x,
+                transform_c.translation.x,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "atlas_sprites")]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn custom_line_height() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("A\nB");
+            tester.image_font_text.line_height = Some(20.0);
+            tester.image_font_text.font_height = Some(2. * MONOSPACE_FONT_HEIGHT as f32);
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+
+            assert_float_eq!(
+                render_context.line_height(),
+                20.0,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_float_eq!(
+                render_context.text_height(),
+                MONOSPACE_FONT_HEIGHT as f32 + 20.0,
+                abs <= COMPARISON_TOLERANCE
+            );
+
+            // The line height is scaled along with the glyphs
+            let layout = render_context.layout();
+            let transform_a = render_context.transform(&layout.lines[0].glyphs[0]);
+            let transform_b = render_context.transform(&layout.lines[1].glyphs[0]);
+            assert_float_eq!(
+                transform_a.translation.y - transform_b.translation.y,
+                40.0,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "atlas_sprites")]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
 fn update_sprite_values() {
+    use bevy::color::palettes::css;
+
     let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
     let render_context_tester = RenderContextTester::new(&app, handle);
 
     render_context_tester.test_with_defaults(|render_context| {
-        let first_char = render_context.text().filtered_chars().next().unwrap();
+        let first_char = render_context.filtered_chars().next().unwrap();
 
-        let mut texture_atlas = render_context.font_texture_atlas('A');
-        let mut color: Color = css::AZURE.into();
+        let mut sprite = Sprite {
+            texture_atlas: Some(render_context.font_texture_atlas('A'.into())),
+            color: css::AZURE.into(),
+            ..default()
+        };
 
         // Make sure the values aren't what we expect after the change before we call
         // update_sprite_values
         assert_ne!(
-            texture_atlas.index,
-            render_context.image_font.atlas_character_map[&first_char].character_index
+            sprite.texture_atlas.as_ref().unwrap().index,
+            render_context.font_texture_atlas(first_char).index
         );
-        assert_ne!(color, render_context.render_config.color);
+        assert_ne!(sprite.color, render_context.render_config.color);
 
-        render_context.update_render_values(first_char, &mut texture_atlas, &mut color);
+        render_context.update_render_values(first_char, &mut sprite);
 
-        // Verify the texture atlas and color are updated
+        // Verify the image, texture atlas and color are updated
         assert_eq!(
-            texture_atlas.index,
-            render_context.image_font.atlas_character_map[&first_char].character_index
+            sprite.texture_atlas.as_ref().unwrap().index,
+            render_context.font_texture_atlas(first_char).index
         );
-        assert_eq!(color, render_context.render_config.color);
+        assert_eq!(sprite.image, render_context.font_image(first_char));
+        assert_eq!(sprite.color, render_context.render_config.color);
     });
 }
 
@@ -357,6 +541,421 @@ fn large_text_block() {
     );
 }
 
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn wraps_at_max_width() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AA AA\nAAAAA");
+            // The max width is given in displayed units, i.e. at twice the size
+            tester.image_font_text.font_height = Some(2. * MONOSPACE_FONT_HEIGHT as f32);
+            tester.image_font_text.max_width = Some(2.5 * 2. * MONOSPACE_FONT_WIDTH as f32);
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let lines: Vec<String> = render_context
+                .layout()
+                .lines
+                .iter()
+                .map(|line| line.glyphs.iter().map(|glyph| glyph.character).collect())
+                .collect();
+
+            assert_eq!(lines, vec!["AA", "AA", "AA", "AA", "A"]);
+            assert_float_eq!(
+                render_context.text_width(),
+                2. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_eq!(render_context.layout().glyphs().count(), 9);
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn aligns_lines() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AAAA\nAA");
+            tester.image_font_text.line_alignment = LineAlignment::Right;
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let second_line = &render_context.layout().lines[1];
+
+            assert_float_eq!(
+                second_line.glyphs[0].x_pos,
+                2. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_float_eq!(
+                render_context.text_width(),
+                4. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn justifies_wrapped_lines() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("A A AAAAA A");
+            tester.image_font_text.max_width = Some(5. * MONOSPACE_FONT_WIDTH as f32);
+            tester.image_font_text.line_alignment = LineAlignment::Justified;
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let lines = &render_context.layout().lines;
+            assert_eq!(lines.len(), 3);
+
+            // "A A" is stretched to the width of "AAAAA"
+            let first_line = &lines[0];
+            assert_float_eq!(
+                first_line.glyphs[2].x_pos,
+                4. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+            // The last line of the paragraph stays left-aligned
+            assert_float_eq!(lines[2].glyphs[0].x_pos, 0., abs <= COMPARISON_TOLERANCE);
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn wraps_kerned_text_within_max_width() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    app.world_mut()
+        .resource_mut::<Assets<ImageFont>>()
+        .get_mut(&handle)
+        .unwrap()
+        .kerning_pairs
+        .insert(('A', 'V'), 2.);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+    let max_width = 2. * MONOSPACE_FONT_WIDTH as f32 + 1.;
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AV AVA");
+            tester.image_font_text.max_width = Some(max_width);
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let lines: Vec<String> = render_context
+                .layout()
+                .lines
+                .iter()
+                .map(|line| line.glyphs.iter().map(|glyph| glyph.character).collect())
+                .collect();
+
+            // Without the kerning, "AV" would fit within the max width
+            assert_eq!(lines, vec!["A", "V", "A", "VA"]);
+            for line in &render_context.layout().lines {
+                assert!(line.width <= max_width, "{} > {max_width}", line.width);
+            }
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn applies_kerning() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    app.world_mut()
+        .resource_mut::<Assets<ImageFont>>()
+        .get_mut(&handle)
+        .unwrap()
+        .kerning_pairs
+        .insert(('A', 'V'), -2.);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AVA");
+            tester.image_font_text.font_height = Some(2. * MONOSPACE_FONT_HEIGHT as f32);
+            tester.render_config.apply_scaling = true;
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let x_positions: Vec<f32> = render_context
+                .layout()
+                .glyphs()
+                .map(|glyph| glyph.x_pos)
+                .collect();
+
+            // The kerning is scaled along with the glyphs, and only applies
+            // to the ('A', 'V') pair, not to ('V', 'A')
+            let scaled_width = 2. * MONOSPACE_FONT_WIDTH as f32;
+            assert_eq!(
+                x_positions,
+                vec![0., scaled_width - 4., 2. * scaled_width - 4.]
+            );
+            assert_float_eq!(
+                render_context.text_width(),
+                3. * scaled_width - 4.,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn advances_by_scaled_x_advance() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    app.world_mut()
+        .resource_mut::<Assets<ImageFont>>()
+        .get_mut(&handle)
+        .unwrap()
+        .atlas_character_map
+        .get_mut(&'A')
+        .unwrap()
+        .x_advance = Some(3.);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AB");
+            tester.image_font_text.font_height = Some(2. * MONOSPACE_FONT_HEIGHT as f32);
+            tester.render_config.apply_scaling = true;
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let x_positions: Vec<f32> = render_context
+                .layout()
+                .glyphs()
+                .map(|glyph| glyph.x_pos)
+                .collect();
+
+            assert_eq!(x_positions, vec![0., 6.]);
+            // The line is as wide as the sum of the advances
+            assert_float_eq!(
+                render_context.text_width(),
+                6. + 2. * MONOSPACE_FONT_WIDTH as f32,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn spans_continue_lines_of_text() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+    let highlight = Color::srgb(1., 0., 0.);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("AB");
+            tester.image_font_text.spans = vec![
+                ImageFontSpan::new("C\nD").color(highlight),
+                // The font of this span is never loaded, so it is left out
+                ImageFontSpan::new("EF").font(Handle::<ImageFont>::default()),
+            ];
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let lines: Vec<Vec<(char, usize)>> = render_context
+                .layout()
+                .lines
+                .iter()
+                .map(|line| {
+                    line.glyphs
+                        .iter()
+                        .map(|glyph| (glyph.character, glyph.span_index))
+                        .collect()
+                })
+                .collect();
+
+            assert_eq!(
+                lines,
+                vec![vec![('A', 0), ('B', 0), ('C', 1)], vec![('D', 1)]]
+            );
+            assert_eq!(
+                render_context.glyph_color(0),
+                render_context.render_config.color
+            );
+            assert_eq!(render_context.glyph_color(1), highlight);
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "atlas_sprites")]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn span_font_height_scales_its_glyphs() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("A");
+            tester.image_font_text.font_height = Some(2. * MONOSPACE_FONT_HEIGHT as f32);
+            tester.image_font_text.spans =
+                vec![ImageFontSpan::new("A").font_height(MONOSPACE_FONT_HEIGHT as f32)];
+            tester.render_config.apply_scaling = true;
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let glyphs: Vec<_> = render_context.layout().glyphs().copied().collect();
+            let width = MONOSPACE_FONT_WIDTH as f32;
+            let height = MONOSPACE_FONT_HEIGHT as f32;
+
+            assert_float_eq!(glyphs[1].x_pos, 2. * width, abs <= COMPARISON_TOLERANCE);
+            assert_float_eq!(
+                render_context.text_width(),
+                3. * width,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_float_eq!(
+                render_context.relative_scale(1),
+                0.5,
+                abs <= COMPARISON_TOLERANCE
+            );
+
+            let text_transform = render_context.transform(&glyphs[0]);
+            let span_transform = render_context.transform(&glyphs[1]);
+            assert_eq!(text_transform.scale, Vec3::new(2., 2., 0.));
+            assert_eq!(span_transform.scale, Vec3::new(1., 1., 0.));
+            // Both glyphs rest on the bottom of the line
+            assert_float_eq!(
+                text_transform.translation.y - height,
+                span_transform.translation.y - height / 2.,
+                abs <= COMPARISON_TOLERANCE
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn span_font_is_used_for_its_glyphs() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let span_font = {
+        let mut image_fonts = app.world_mut().resource_mut::<Assets<ImageFont>>();
+        let mut span_font = image_fonts.get(&handle).unwrap().clone();
+        // The span's font draws 'A' using the glyph of 'B'
+        span_font.atlas_character_map = [('A', span_font.atlas_character_map[&'B'].clone())]
+            .into_iter()
+            .collect();
+        image_fonts.add(span_font)
+    };
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| {
+            tester.image_font_text.text = String::from("A");
+            tester.image_font_text.spans = vec![ImageFontSpan::new("AC").font(span_font)];
+        },
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let characters: Vec<_> = render_context.filtered_chars().collect();
+
+            // 'C' isn't in the span's font
+            assert_eq!(characters.len(), 2);
+            assert_eq!(
+                render_context.font_texture_atlas(characters[1]).index,
+                render_context
+                    .font_texture_atlas(SpanCharacter::from('B'))
+                    .index
+            );
+            assert_ne!(
+                render_context.font_texture_atlas(characters[0]).index,
+                render_context.font_texture_atlas(characters[1]).index
+            );
+        },
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "the magnitude of the numbers we're working on here are too small to lose \
+        anything"
+)]
+fn inserted_image_glyph_is_laid_out_like_a_character() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let icon_size = UVec2::new(8, 20);
+    app.world_mut()
+        .resource_scope(|world, mut image_fonts: Mut<Assets<ImageFont>>| {
+            let mut atlas_layouts = world.resource_mut::<Assets<TextureAtlasLayout>>();
+            image_fonts.get_mut(&handle).unwrap().insert_image_glyph(
+                '\u{e000}',
+                Handle::default(),
+                icon_size,
+                None,
+                &mut atlas_layouts,
+            );
+        });
+    let render_context_tester = RenderContextTester::new(&app, handle);
+
+    render_context_tester.modify_and_then_test_with(
+        |tester| tester.image_font_text.text = String::from("A\u{e000}A"),
+        |render_context| {
+            let render_context = render_context.unwrap();
+            let (icon_width, icon_height) = render_context.character_dimensions('\u{e000}'.into());
+
+            assert_float_eq!(icon_width, icon_size.x as f32, abs <= COMPARISON_TOLERANCE);
+            assert_float_eq!(icon_height, icon_size.y as f32, abs <= COMPARISON_TOLERANCE);
+            assert_float_eq!(
+                render_context.text_width(),
+                MONOSPACE_FONT_WIDTH as f32 * 2. + icon_width,
+                abs <= COMPARISON_TOLERANCE
+            );
+            assert_eq!(render_context.max_height(), icon_size.y);
+        },
+    );
+}
+
 #[derive(Clone)]
 struct RenderContextTester<'app> {
     image_font_text: ImageFontText,
@@ -370,7 +969,13 @@ impl<'app> RenderContextTester<'app> {
         let image_font_text = ImageFontText {
             text: String::from("Test"),
             font: handle.clone_weak(),
+            fallback_fonts: Vec::new(),
             font_height: None,
+            font_height_reference: default(),
+            line_height: None,
+            max_width: None,
+            line_alignment: default(),
+            spans: Vec::new(),
         };
 
         let image_font_assets = app.world().resource::<Assets<ImageFont>>();
@@ -394,6 +999,7 @@ impl<'app> RenderContextTester<'app> {
 
         let render_context = RenderContext::new(
             image_font,
+            self.image_font_assets,
             &self.image_font_text,
             self.render_config,
             self.atlas_layout_assets,
@@ -417,6 +1023,7 @@ impl<'app> RenderContextTester<'app> {
 
         let render_context = RenderContext::new(
             image_font,
+            modified_clone.image_font_assets,
             &modified_clone.image_font_text,
             modified_clone.render_config,
             modified_clone.atlas_layout_assets,
@@ -0,0 +1,394 @@
+//! This module provides the [`TextLayout`] type, which describes how the
+//! characters of a text are split into lines and where each glyph is placed
+//! horizontally within its line.
+//!
+//! The layout is computed once per [`RenderContext`](super::RenderContext) and
+//! shared by both the `atlas_sprites` and the `rendered` code paths, so that
+//! line breaking behaves identically regardless of how the text ends up being
+//! drawn.
+//!
+//! It also provides [`wrap_words`], which breaks a line of text into several
+//! lines that each fit within a maximum width, and [`TextLayout::align`],
+//! which aligns the lines of a layout horizontally.
+//!
+//! Horizontal positions and widths are given in the same units as
+//! [`RenderContext::character_dimensions`](super::RenderContext::character_dimensions),
+//! i.e. they are scaled if the render configuration applies scaling.
+
+use std::mem;
+
+use crate::LineAlignment;
+
+/// The result of laying out a text: the lines it consists of and where each
+/// glyph goes within them.
+#[derive(Debug, Default)]
+pub(crate) struct TextLayout {
+    /// The lines of the text, in order from top to bottom. There is always at
+    /// least one line, even for empty text.
+    pub lines: Vec<TextLine>,
+}
+
+impl TextLayout {
+    /// Returns the width of the widest line in the layout.
+    #[inline]
+    pub(crate) fn width(&self) -> f32 {
+        self.lines.iter().map(|line| line.width).fold(0.0, f32::max)
+    }
+
+    /// Returns an iterator over all glyphs in the layout, in text order.
+    #[inline]
+    pub(crate) fn glyphs(&self) -> impl Iterator<Item = &PlacedGlyph> + '_ {
+        self.lines.iter().flat_map(|line| line.glyphs.iter())
+    }
+
+    /// Aligns the lines of the layout horizontally within the width of its
+    /// widest line.
+    ///
+    /// Left-aligned lines are left untouched. Centered and right-aligned lines
+    /// have all their glyphs moved to the right by the appropriate fraction of
+    /// the space they leave empty. Justified lines are widened to the full
+    /// width by distributing that space evenly over their whitespace, except
+    /// for lines ending a paragraph and lines without any whitespace.
+    ///
+    /// # Parameters
+    /// - `alignment`: The alignment to apply to every line.
+    pub(crate) fn align(&mut self, alignment: LineAlignment) {
+        let width = self.width();
+
+        for line in &mut self.lines {
+            let free_space = width - line.width;
+            if free_space <= 0. {
+                continue;
+            }
+
+            if alignment == LineAlignment::Justified {
+                line.justify(width);
+                continue;
+            }
+
+            let offset = free_space * alignment.offset_factor();
+            for glyph in &mut line.glyphs {
+                glyph.x_pos += offset;
+            }
+        }
+    }
+}
+
+/// A single line of laid out text.
+#[derive(Debug, Default)]
+pub(crate) struct TextLine {
+    /// The glyphs on this line, in order from left to right.
+    pub glyphs: Vec<PlacedGlyph>,
+    /// The total width of the glyphs on this line.
+    pub width: f32,
+    /// Whether this line is the last one of its paragraph, i.e. whether it is
+    /// followed by a `\n` or the end of the text rather than a wrapped line.
+    pub ends_paragraph: bool,
+}
+
+impl TextLine {
+    /// Widens the line to the given width by distributing the extra space
+    /// evenly over the gaps at its whitespace characters.
+    ///
+    /// Lines that end a paragraph or don't contain any whitespace are left
+    /// untouched.
+    ///
+    /// # Parameters
+    /// - `width`: The width the line should be stretched to.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "the number of gaps in a line won't ever be particularly large"
+    )]
+    fn justify(&mut self, width: f32) {
+        let gaps = self
+            .glyphs
+            .iter()
+            .filter(|glyph| is_break_opportunity(glyph.character))
+            .count();
+        if self.ends_paragraph || gaps == 0 {
+            return;
+        }
+
+        let gap_width = (width - self.width) / gaps as f32;
+        let mut offset = 0.;
+        for glyph in &mut self.glyphs {
+            glyph.x_pos += offset;
+            if is_break_opportunity(glyph.character) {
+                offset += gap_width;
+            }
+        }
+        self.width = width;
+    }
+}
+
+/// A character of the text, together with the span of the text it belongs
+/// to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SpanCharacter {
+    /// The character itself.
+    pub character: char,
+    /// The index of the span the character belongs to: `0` for the text's own
+    /// `text`, and `n` for its `n`th span.
+    pub span_index: usize,
+}
+
+impl From<char> for SpanCharacter {
+    /// Creates a `SpanCharacter` for a character of the text's own `text`.
+    fn from(character: char) -> Self {
+        Self {
+            character,
+            span_index: 0,
+        }
+    }
+}
+
+impl From<SpanCharacter> for char {
+    fn from(span_character: SpanCharacter) -> Self {
+        span_character.character
+    }
+}
+
+/// A glyph together with its position in a [`TextLayout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct PlacedGlyph {
+    /// The character this glyph represents.
+    pub character: char,
+    /// The index of the span of the text the character belongs to.
+    pub span_index: usize,
+    /// The x-position of the glyph relative to the start of its line.
+    pub x_pos: f32,
+    /// The index of the line this glyph is on, counting from the top.
+    pub line_index: usize,
+}
+
+impl PlacedGlyph {
+    /// Returns the character this glyph represents, along with its span.
+    #[inline]
+    pub(crate) const fn span_character(&self) -> SpanCharacter {
+        SpanCharacter {
+            character: self.character,
+            span_index: self.span_index,
+        }
+    }
+}
+
+/// Breaks a line of characters into several lines that each fit within
+/// `max_width`.
+///
+/// Lines are broken at whitespace, which is dropped at the point of the
+/// break. Words that are too long to fit on a line of their own are broken
+/// between characters instead. Every line holds at least one character, so a
+/// single character wider than `max_width` still gets a line of its own.
+///
+/// # Parameters
+/// - `characters`: The characters of the line to wrap, either as plain `char`s
+///   or along with additional data, such as their
+///   [`SpanCharacter::span_index`].
+/// - `max_width`: The maximum width of a line.
+/// - `width_of`: Returns the width a character adds to a line, given the
+///   character preceding it on the line, if any. This includes any kerning
+///   between the two, so lines are measured the same way they are laid out.
+///
+/// # Returns
+/// The wrapped lines, in order. There is always at least one line, which is
+/// empty if `characters` is.
+pub(crate) fn wrap_words<C: Copy + Into<char>>(
+    characters: impl Iterator<Item = C>,
+    max_width: f32,
+    width_of: impl Fn(Option<C>, C) -> f32,
+) -> Vec<Vec<C>> {
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+    let mut line_width = 0.;
+    // Index into `line` of the last whitespace the line could be broken at.
+    let mut last_break = None;
+
+    for character in characters {
+        let overflows = |line_width: f32, line: &Vec<C>| {
+            !line.is_empty() && line_width + width_of(line.last().copied(), character) > max_width
+        };
+
+        if is_break_opportunity(character.into()) {
+            if overflows(line_width, &line) {
+                lines.push(mem::take(&mut line));
+                line_width = 0.;
+                last_break = None;
+            } else {
+                last_break = Some(line.len());
+                line_width += width_of(line.last().copied(), character);
+                line.push(character);
+            }
+            continue;
+        }
+
+        if overflows(line_width, &line) {
+            if let Some(break_index) = last_break.take() {
+                let rest = line.split_off(break_index + 1);
+                line.pop();
+                lines.push(mem::replace(&mut line, rest));
+                line_width = measure_line(&line, &width_of);
+            }
+            if overflows(line_width, &line) {
+                lines.push(mem::take(&mut line));
+                line_width = 0.;
+            }
+        }
+
+        line_width += width_of(line.last().copied(), character);
+        line.push(character);
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Measures the width of a line of characters.
+///
+/// # Parameters
+/// - `line`: The characters of the line.
+/// - `width_of`: Returns the width a character adds to a line, given the
+///   character preceding it on the line, if any.
+///
+/// # Returns
+/// The sum of the widths of the characters on the line.
+fn measure_line<C: Copy>(line: &[C], width_of: impl Fn(Option<C>, C) -> f32) -> f32 {
+    let mut previous = None;
+    line.iter()
+        .map(|&character| width_of(previous.replace(character), character))
+        .sum()
+}
+
+/// Determines whether a line may be broken at the given character.
+///
+/// This is true for whitespace, except for the non-breaking space characters.
+#[inline]
+fn is_break_opportunity(character: char) -> bool {
+    character.is_whitespace() && !matches!(character, '\u{a0}' | '\u{2007}' | '\u{202f}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrap(text: &str, max_width: f32) -> Vec<String> {
+        wrap_words(text.chars(), max_width, |_, _| 1.0)
+            .into_iter()
+            .map(|line| line.into_iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn short_line_is_not_wrapped() {
+        assert_eq!(wrap("hello world", 11.0), vec!["hello world"]);
+    }
+
+    #[test]
+    fn wraps_at_whitespace() {
+        assert_eq!(
+            wrap("the quick brown fox", 10.0),
+            vec!["the quick", "brown fox"]
+        );
+        // A space that would overflow the line is dropped
+        assert_eq!(wrap("abc def", 3.0), vec!["abc", "def"]);
+    }
+
+    #[test]
+    fn breaks_overlong_words() {
+        assert_eq!(wrap("abcdefgh", 3.0), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("a bcdefgh", 3.0), vec!["a", "bcd", "efg", "h"]);
+    }
+
+    #[test]
+    fn measures_kerning_between_characters() {
+        let width_of = |previous: Option<char>, character: char| {
+            let kerning = if previous == Some('a') && character == 'b' {
+                1.0
+            } else {
+                0.0
+            };
+            kerning + 1.0
+        };
+        let lines: Vec<String> = wrap_words("ab ab".chars(), 2.5, width_of)
+            .into_iter()
+            .map(|line| line.into_iter().collect())
+            .collect();
+
+        assert_eq!(lines, vec!["a", "b", "a", "b"]);
+    }
+
+    #[test]
+    fn does_not_break_at_non_breaking_space() {
+        assert_eq!(wrap("ab\u{a0}cd ef", 5.0), vec!["ab\u{a0}cd", "ef"]);
+    }
+
+    fn layout(lines: &[(&str, bool)]) -> TextLayout {
+        let lines = lines
+            .iter()
+            .enumerate()
+            .map(|(line_index, &(text, ends_paragraph))| TextLine {
+                glyphs: text
+                    .chars()
+                    .zip(0_u16..)
+                    .map(|(character, x_pos)| PlacedGlyph {
+                        character,
+                        span_index: 0,
+                        x_pos: f32::from(x_pos),
+                        line_index,
+                    })
+                    .collect(),
+                width: f32::from(u16::try_from(text.len()).unwrap()),
+                ends_paragraph,
+            })
+            .collect();
+        TextLayout { lines }
+    }
+
+    fn x_positions(layout: &TextLayout, line_index: usize) -> Vec<f32> {
+        layout.lines[line_index]
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.x_pos)
+            .collect()
+    }
+
+    #[test]
+    fn aligns_lines() {
+        let mut left = layout(&[("abcd", true), ("ab", true)]);
+        left.align(LineAlignment::Left);
+        assert_eq!(x_positions(&left, 0), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(x_positions(&left, 1), vec![0.0, 1.0]);
+
+        let mut center = layout(&[("abcd", true), ("ab", true)]);
+        center.align(LineAlignment::Center);
+        assert_eq!(x_positions(&center, 0), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(x_positions(&center, 1), vec![1.0, 2.0]);
+
+        let mut right = layout(&[("abcd", true), ("ab", true)]);
+        right.align(LineAlignment::Right);
+        assert_eq!(x_positions(&right, 0), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(x_positions(&right, 1), vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn justifies_lines() {
+        let mut justified = layout(&[("abcdefgh", false), ("a b c", false), ("a b", true)]);
+        justified.align(LineAlignment::Justified);
+        // 3 units of free space spread over 2 gaps
+        assert_eq!(x_positions(&justified, 1), vec![0.0, 1.0, 3.5, 4.5, 7.0]);
+        assert!((justified.lines[1].width - 8.0).abs() < f32::EPSILON);
+        // The last line of a paragraph isn't stretched
+        assert_eq!(x_positions(&justified, 2), vec![0.0, 1.0, 2.0]);
+
+        // Lines without whitespace aren't stretched either
+        let mut no_gaps = layout(&[("abcd", false), ("ab", false)]);
+        no_gaps.align(LineAlignment::Justified);
+        assert_eq!(x_positions(&no_gaps, 1), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn empty_and_tiny_widths() {
+        assert_eq!(wrap("", 5.0), vec![""]);
+        // Every line holds at least one character
+        assert_eq!(wrap("ab", 0.0), vec!["a", "b"]);
+    }
+}
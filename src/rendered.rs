@@ -6,22 +6,29 @@
 //! Key Features:
 //! - `ImageFontPreRenderedText` and `ImageFontPreRenderedUiText` components for
 //!   in-world and UI text rendering, respectively.
+//! - `ImageFontPreRenderedStyle` component for customizing the look of the
+//!   rendered text, such as its color and letter spacing.
 //! - Systems for rendering text updates to `Sprite` or `ImageNode` components
 //!   dynamically.
 //! - Integrates with the `image` crate for low-level image manipulation.
 
 use bevy::sprite::Anchor;
 use bevy::{
+    platform::collections::HashMap,
     prelude::*,
     render::{
-        render_asset::RenderAssetUsages,
-        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        render_asset::{RenderAssetUsages, RenderAssets},
+        render_resource::{Extent3d, TexelCopyBufferLayout, TextureDimension, TextureFormat},
+        renderer::RenderQueue,
+        texture::GpuImage,
+        ExtractSchedule, MainWorld, Render, RenderApp, RenderSet,
     },
 };
 use bevy_image::{Image, ImageSampler};
+use derive_setters::Setters;
 use image::{
     imageops::{self, FilterType},
-    GenericImage as _, GenericImageView as _, ImageBuffer, ImageError, Rgba,
+    GenericImageView as _, ImageBuffer, ImageError, Rgba,
 };
 use thiserror::Error;
 use tracing::{debug, error};
@@ -29,6 +36,7 @@ use tracing::{debug, error};
 use crate::render_context::{RenderConfig, RenderContext};
 use crate::{
     sync_texts_with_font_changes, FontScalingMode, ImageFont, ImageFontSet, ImageFontText,
+    LetterSpacing,
 };
 
 /// Internal plugin for conveniently organizing the code related to this
@@ -57,6 +65,22 @@ impl Plugin for RenderedPlugin {
             );
         }
     }
+
+    fn finish(&self, app: &mut App) {
+        // Without a renderer, e.g. in headless apps, images are updated in
+        // their assets instead
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<RenderedImageWrites>()
+            .add_systems(ExtractSchedule, extract_rendered_image_writes)
+            .add_systems(
+                Render,
+                write_rendered_images.in_set(RenderSet::PrepareResources),
+            );
+        app.init_resource::<RenderedImageWrites>();
+    }
 }
 
 /// A component for displaying in-world text that has been pre-rendered using an
@@ -65,10 +89,19 @@ impl Plugin for RenderedPlugin {
 /// This component requires an `ImageFontText` component for determining its
 /// font and text. It renders its text into an image and sets it as the texture
 /// on its `Sprite` component.
+///
+/// The image is created the first time the text is rendered. Whenever the text
+/// changes afterwards without changing the size of the image, the new pixels
+/// are written into the image's existing texture on the GPU, as long as the
+/// `Sprite` still
+/// uses it.
 #[derive(Component, Debug, Default, Clone, Reflect)]
-#[require(ImageFontText, Sprite)]
+#[require(ImageFontText, ImageFontPreRenderedStyle, Sprite)]
 #[non_exhaustive]
-pub struct ImageFontPreRenderedText;
+pub struct ImageFontPreRenderedText {
+    /// The image most recently rendered for this text, if any.
+    rendered_image: Option<RenderedImage>,
+}
 
 /// A component for displaying UI text that has been pre-rendered using an image
 /// font.
@@ -76,46 +109,187 @@ pub struct ImageFontPreRenderedText;
 /// This component requires an `ImageFontText` component for determining its
 /// font and text. It renders its text into an image and sets it as the texture
 /// on its `ImageNode` component.
+///
+/// The image is created the first time the text is rendered. Whenever the text
+/// changes afterwards without changing the size of the image, the new pixels
+/// are written into the image's existing texture on the GPU, as long as the
+/// `ImageNode`
+/// still uses it.
 #[derive(Component, Debug, Default, Clone, Reflect)]
 #[cfg(feature = "ui")]
-#[require(ImageFontText, ImageNode)]
+#[require(ImageFontText, ImageFontPreRenderedStyle, ImageNode)]
+#[non_exhaustive]
+pub struct ImageFontPreRenderedUiText {
+    /// The image most recently rendered for this text, if any.
+    rendered_image: Option<RenderedImage>,
+}
+
+/// An image rendered for the text of an [`ImageFontPreRenderedText`] or
+/// [`ImageFontPreRenderedUiText`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+struct RenderedImage {
+    /// The image asset.
+    id: AssetId<Image>,
+    /// The size of the image, which its texture on the GPU was created with.
+    size: UVec2,
+}
+
+/// The pixels of rendered images to write into their existing textures on the
+/// GPU.
+///
+/// Rendered images only live in the render world once they've been sent to
+/// the GPU, so changes to them can't go through their assets without creating
+/// a new texture every time. Instead, the pixels are collected here in the
+/// main world, moved to the render world during extraction and written into the
+/// textures before rendering.
+#[derive(Resource, Debug, Default)]
+pub struct RenderedImageWrites(Vec<(AssetId<Image>, Vec<u8>)>);
+
+/// Moves the pixels of the rendered images that changed this frame from the
+/// main world to the render world.
+fn extract_rendered_image_writes(
+    mut main_world: ResMut<MainWorld>,
+    mut writes: ResMut<RenderedImageWrites>,
+) {
+    if let Some(mut main_world_writes) = main_world.get_resource_mut::<RenderedImageWrites>() {
+        writes.0.append(&mut main_world_writes.0);
+    }
+}
+
+/// Writes the pixels of rendered images that changed into their existing
+/// textures on the GPU.
+fn write_rendered_images(
+    mut writes: ResMut<RenderedImageWrites>,
+    gpu_images: Res<RenderAssets<GpuImage>>,
+    render_queue: Res<RenderQueue>,
+) {
+    for (id, data) in writes.0.drain(..) {
+        // The image was dropped in the meantime
+        let Some(gpu_image) = gpu_images.get(id) else {
+            continue;
+        };
+        render_queue.write_texture(
+            gpu_image.texture.as_image_copy(),
+            &data,
+            TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(gpu_image.size.width * 4),
+                rows_per_image: None,
+            },
+            gpu_image.size,
+        );
+    }
+}
+
+/// Customizes how the text of an [`ImageFontPreRenderedText`] or
+/// [`ImageFontPreRenderedUiText`] is rendered into its image.
+///
+/// This provides the same customization options as `ImageFontSpriteText`
+/// does for atlas-based text, so the two ways of rendering text can be made to
+/// look the same.
+///
+/// - `color`: Uniform tint applied to all glyphs.
+/// - `scaling_mode`: Controls how scaling is applied to the image dimensions.
+/// - `letter_spacing`: Adds extra space between the characters of the text.
+#[derive(Debug, Clone, Reflect, Default, Component, Setters)]
+#[setters(into)]
 #[non_exhaustive]
-pub struct ImageFontPreRenderedUiText;
+pub struct ImageFontPreRenderedStyle {
+    /// The color applied to the rendered text. This color is multiplied into
+    /// the pixels of the glyphs as they're rendered, the same way a `Sprite`'s
+    /// color tints its texture. Spans of the text with a
+    /// [`color`](crate::ImageFontSpan::color) of their own are tinted with that
+    /// color instead.
+    pub color: Color,
+
+    /// Determines how scaling is applied to the dimensions of the rendered
+    /// image when adjusting it to match the desired font height. Since an
+    /// image's size is a whole number of pixels, [`FontScalingMode::Smooth`]
+    /// rounds up so no part of the text is cut off.
+    pub scaling_mode: FontScalingMode,
+
+    /// Specifies the amount of spacing between characters, in unscaled font
+    /// pixels.
+    pub letter_spacing: LetterSpacing,
+}
+
+/// Query filter matching the entities whose text needs to be rendered again.
+type ChangedText = Or<(Changed<ImageFontText>, Changed<ImageFontPreRenderedStyle>)>;
 
 /// System that renders each [`ImageFontText`] into its [`Sprite`]. This system
-/// only runs when the `ImageFontText` changes.
+/// only runs when the `ImageFontText` or its [`ImageFontPreRenderedStyle`]
+/// changes.
 pub fn render_text_to_sprite(
-    mut query: Query<(&ImageFontText, &mut Sprite), Changed<ImageFontText>>,
+    mut query: Query<
+        (
+            &ImageFontText,
+            Option<&ImageFontPreRenderedStyle>,
+            Option<&mut ImageFontPreRenderedText>,
+            &mut Sprite,
+        ),
+        ChangedText,
+    >,
     image_fonts: Res<Assets<ImageFont>>,
     mut images: ResMut<Assets<Image>>,
     layouts: Res<Assets<TextureAtlasLayout>>,
+    mut writes: Option<ResMut<RenderedImageWrites>>,
 ) {
     render_text_to_image_holder(
         query
             .iter_mut()
-            .map(|(image_font_text, sprite)| (image_font_text, sprite.into_inner())),
+            .map(|(image_font_text, style, pre_rendered_text, sprite)| {
+                (
+                    image_font_text,
+                    style,
+                    pre_rendered_text.map(|pre_rendered_text| {
+                        &mut pre_rendered_text.into_inner().rendered_image
+                    }),
+                    sprite.into_inner(),
+                )
+            }),
         &image_fonts,
         &mut images,
         &layouts,
+        writes.as_deref_mut(),
     );
 }
 
 #[cfg(feature = "ui")]
 /// System that renders each [`ImageFontText`] into its [`ImageNode`]. This
-/// system only runs when the `ImageFontText` changes.
+/// system only runs when the `ImageFontText` or its
+/// [`ImageFontPreRenderedStyle`] changes.
 pub fn render_text_to_image_node(
-    mut query: Query<(&ImageFontText, &mut ImageNode), Changed<ImageFontText>>,
+    mut query: Query<
+        (
+            &ImageFontText,
+            Option<&ImageFontPreRenderedStyle>,
+            Option<&mut ImageFontPreRenderedUiText>,
+            &mut ImageNode,
+        ),
+        ChangedText,
+    >,
     image_fonts: Res<Assets<ImageFont>>,
     mut images: ResMut<Assets<Image>>,
     layouts: Res<Assets<TextureAtlasLayout>>,
+    mut writes: Option<ResMut<RenderedImageWrites>>,
 ) {
     render_text_to_image_holder(
         query
             .iter_mut()
-            .map(|(image_font_text, image_node)| (image_font_text, image_node.into_inner())),
+            .map(|(image_font_text, style, pre_rendered_text, image_node)| {
+                (
+                    image_font_text,
+                    style,
+                    pre_rendered_text.map(|pre_rendered_text| {
+                        &mut pre_rendered_text.into_inner().rendered_image
+                    }),
+                    image_node.into_inner(),
+                )
+            }),
         &image_fonts,
         &mut images,
         &layouts,
+        writes.as_deref_mut(),
     );
 }
 
@@ -127,17 +301,25 @@ pub fn render_text_to_image_node(
 /// as sprites and UI elements.
 ///
 /// # Parameters
-/// - `font_text_to_image_iter`: An iterator over pairs of [`ImageFontText`] and
-///   mutable references to objects implementing [`ImageHandleHolder`]. Each
-///   item in the iterator represents a text-to-image mapping to be rendered.
+/// - `font_text_to_image_iter`: An iterator over [`ImageFontText`]s, their
+///   optional [`ImageFontPreRenderedStyle`]s, the images previously rendered
+///   for them, if they keep track of those, and mutable references to objects
+///   implementing [`ImageHandleHolder`]. Each item in the iterator represents a
+///   text-to-image mapping to be rendered. Text without a style is rendered
+///   using the default style.
 /// - `image_fonts`: A reference to the font assets used for rendering.
 /// - `images`: A mutable reference to the collection of image assets. This is
 ///   used to store the newly rendered images.
 /// - `layouts`: A reference to the collection of texture atlas assets.
+/// - `writes`: The pixels to write into existing textures on the GPU, or `None`
+///   if the app doesn't render anything.
 ///
-/// The function iterates over the provided items, renders the text for each
-/// [`ImageFontText`], and updates the corresponding [`ImageHandleHolder`] with
-/// the handle to the newly created image.
+/// The function iterates over the provided items and renders the text for each
+/// [`ImageFontText`]. If the corresponding [`ImageHandleHolder`] still holds
+/// the image previously rendered for the text and the image's size hasn't
+/// changed, the new pixels are written into the image's existing texture.
+/// Otherwise, a new image asset is created and its handle is given to the
+/// holder.
 ///
 /// # Errors
 /// If text rendering fails for an item (e.g., due to missing font assets
@@ -147,18 +329,48 @@ fn render_text_to_image_holder<'borrow>(
     font_text_to_image_iter: impl Iterator<
         Item = (
             &'borrow ImageFontText,
+            Option<&'borrow ImageFontPreRenderedStyle>,
+            Option<&'borrow mut Option<RenderedImage>>,
             &'borrow mut (impl ImageHandleHolder + 'borrow),
         ),
     >,
     image_fonts: &Assets<ImageFont>,
     images: &mut Assets<Image>,
     layouts: &Assets<TextureAtlasLayout>,
+    mut writes: Option<&mut RenderedImageWrites>,
 ) {
-    for (image_font_text, image_handle_holder) in font_text_to_image_iter {
+    for (image_font_text, style, rendered_image, image_handle_holder) in font_text_to_image_iter {
         debug!("Rendering [{}]", image_font_text.text);
-        match render_text_to_image(image_font_text, image_fonts, images, layouts) {
+        let style = style.cloned().unwrap_or_default();
+        match render_text_to_image(image_font_text, &style, image_fonts, images, layouts) {
             Ok(image) => {
-                image_handle_holder.set_image_handle(images.add(image));
+                let rendered = RenderedImage {
+                    id: image_handle_holder.image_handle().id(),
+                    size: image.size(),
+                };
+                let reusable = rendered_image
+                    .as_deref()
+                    .is_some_and(|&previous| previous == Some(rendered));
+
+                if reusable {
+                    if let Some(writes) = writes.as_deref_mut() {
+                        writes.0.push((rendered.id, image.data.unwrap_or_default()));
+                        continue;
+                    }
+                    if let Some(existing_image) = images.get_mut(rendered.id) {
+                        *existing_image = image;
+                        continue;
+                    }
+                }
+
+                let handle = images.add(image);
+                if let Some(rendered_image) = rendered_image {
+                    *rendered_image = Some(RenderedImage {
+                        id: handle.id(),
+                        ..rendered
+                    });
+                }
+                image_handle_holder.set_image_handle(handle);
             }
             Err(error) => {
                 error!(
@@ -179,6 +391,8 @@ fn render_text_to_image_holder<'borrow>(
 ///
 /// # Parameters
 /// - `image_font_text`: The text to render, along with its associated font.
+/// - `style`: The color, scaling mode and letter spacing to render the text
+///   with.
 /// - `image_fonts`: The collection of available font assets.
 /// - `images`: The collection of image assets used to retrieve font textures.
 /// - `layouts`: The texture atlas layouts defining character positioning.
@@ -192,13 +406,19 @@ fn render_text_to_image_holder<'borrow>(
 ///   invalid texture sizes.
 /// - The function leverages [`RenderContext`] to compute character positions
 ///   and generate the image.
+/// - Glyphs are placed according to their advances and offsets, like they are
+///   by the `atlas_sprites` path. The image covers the text block as well as
+///   any glyph extending beyond it, such as descenders or glyphs with negative
+///   offsets.
 #[expect(
     clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
     clippy::cast_sign_loss,
     reason = "numbers are always positive and small enough"
 )]
 fn render_text_to_image(
     image_font_text: &ImageFontText,
+    style: &ImageFontPreRenderedStyle,
     image_fonts: &Assets<ImageFont>,
     images: &Assets<Image>,
     layouts: &Assets<TextureAtlasLayout>,
@@ -206,22 +426,26 @@ fn render_text_to_image(
     let image_font = image_fonts
         .get(&image_font_text.font)
         .ok_or(ImageFontRenderError::MissingImageFontAsset)?;
-    let textures = image_font.textures(images);
 
     let render_config = RenderConfig {
         text_anchor: Anchor::Center,
         offset_characters: false,
         apply_scaling: false,
-        letter_spacing: 0.0,
-        scaling_mode: FontScalingMode::Truncated,
-        color: Color::WHITE, // Currently unused for rendering to an image
+        letter_spacing: style.letter_spacing.to_f32(),
+        scaling_mode: style.scaling_mode,
+        color: style.color,
     };
 
-    let mut render_context =
-        RenderContext::new(image_font, image_font_text, render_config, layouts)
-            .ok_or(ImageFontRenderError::MissingTextureAsset)?;
+    let render_context = RenderContext::new(
+        image_font,
+        image_fonts,
+        image_font_text,
+        render_config,
+        layouts,
+    )
+    .ok_or(ImageFontRenderError::MissingTextureAsset)?;
 
-    if render_context.text().is_empty() {
+    if render_context.is_empty() {
         // Can't make a 0x0 image, so make a 1x1 transparent black pixel
         return Ok(Image::new(
             Extent3d {
@@ -236,66 +460,60 @@ fn render_text_to_image(
         ));
     }
 
-    let width = render_context.text_width() as u32;
-    let height = render_context.max_height();
+    let placements = glyph_placements(&render_context, layouts)?;
+    let bounds = placements.iter().fold(
+        Rect::new(
+            0.,
+            0.,
+            render_context.text_width(),
+            render_context.text_height(),
+        ),
+        |bounds, placement| bounds.union(placement.target),
+    );
 
-    let mut output_image = image::RgbaImage::new(width, height);
-    let font_textures: Vec<ImageBuffer<Rgba<u8>, _>> = textures
-        .iter()
-        .map(|texture| {
-            let flat_data: Vec<u8> = texture
-                .data
-                .iter()
-                .flat_map(|v| v.iter().copied())
-                .collect();
-            ImageBuffer::from_raw(texture.width(), texture.height(), flat_data)
-        })
-        .collect::<Option<_>>()
-        .ok_or(ImageFontRenderError::UnknownError)?;
-
-    let mut x_pos = 0.0;
-    let mut texture_atlas = render_context.font_texture_atlas(' ');
-    let mut color = Color::default();
-    for character in render_context.text().filtered_chars() {
-        let image_font_character = &image_font.atlas_character_map[&character];
-        render_context.update_render_values(character, &mut texture_atlas, &mut color);
-
-        #[expect(
-            clippy::expect_used,
-            reason = "we're using `filtered_chars()` which has only valid characters"
-        )]
-        let rect = texture_atlas
-            .texture_rect(layouts)
-            .expect("`filtered_chars()` guarantees valid characters");
-
-        output_image.copy_from(
-            &*font_textures[image_font_character.page_index].view(
-                rect.min.x,
-                rect.min.y,
-                rect.width(),
-                rect.height(),
-            ),
-            x_pos as u32,
-            0,
-        )?;
-
-        // Let `transform()` handle x-position updates
-        render_context.transform(&mut x_pos, character);
+    let mut output_image =
+        image::RgbaImage::new(bounds.width().ceil() as u32, bounds.height().ceil() as u32);
+    let mut font_textures = HashMap::new();
+    for placement in &placements {
+        if !font_textures.contains_key(&placement.texture) {
+            let texture = images
+                .get(placement.texture)
+                .ok_or(ImageFontRenderError::MissingTextureAsset)?;
+            font_textures.insert(placement.texture, font_texture_buffer(texture)?);
+        }
     }
 
-    #[expect(
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss,
-        reason = "the magnitude of the numbers we're working on here are too small to lose anything"
-    )]
-    if let Some(font_height) = image_font_text.font_height {
-        render_context.render_config.apply_scaling = true;
-        let scaled_width = render_context.text_width();
+    for placement in &placements {
+        let source = placement.source;
+        let target = placement.target.min - bounds.min;
+        let mut glyph = font_textures[&placement.texture]
+            .view(source.min.x, source.min.y, source.width(), source.height())
+            .to_image();
+        // Glyphs of spans with a different font or height than the text are
+        // resized to match the rest of it
+        let target_size = placement.target.size().round().max(Vec2::ONE).as_uvec2();
+        if target_size != source.size() {
+            glyph = imageops::resize(&glyph, target_size.x, target_size.y, FilterType::Nearest);
+        }
+        tint_image(&mut glyph, placement.color);
+        // Glyphs may overlap, e.g. with negative letter spacing, so they're
+        // blended onto the image rather than copied over each other
+        imageops::overlay(&mut output_image, &glyph, target.x as i64, target.y as i64);
+    }
 
+    if image_font_text.font_height.is_some() {
+        let scale = render_context.scale();
+        let scaled_size = |size: u32| {
+            style
+                .scaling_mode
+                .apply_scale(size as f32, scale)
+                .ceil()
+                .max(1.) as u32
+        };
         output_image = imageops::resize(
             &output_image,
-            scaled_width as u32,
-            font_height as u32,
+            scaled_size(output_image.width()),
+            scaled_size(output_image.height()),
             FilterType::Nearest,
         );
     }
@@ -316,6 +534,133 @@ fn render_text_to_image(
     Ok(bevy_image)
 }
 
+/// Multiplies every pixel of the image by the given color.
+///
+/// The multiplication happens in linear color space, which is how the
+/// renderer applies a `Sprite`'s color to its texture.
+///
+/// # Parameters
+/// - `image`: The image to tint.
+/// - `color`: The color to tint the image with. White leaves it unchanged.
+fn tint_image(image: &mut image::RgbaImage, color: Color) {
+    if color == Color::WHITE {
+        return;
+    }
+
+    let tint = color.to_linear();
+    for pixel in image.pixels_mut() {
+        let [red, green, blue, alpha] = pixel.0;
+        let linear = LinearRgba::from(Srgba::rgba_u8(red, green, blue, alpha));
+        let tinted = LinearRgba::new(
+            linear.red * tint.red,
+            linear.green * tint.green,
+            linear.blue * tint.blue,
+            linear.alpha * tint.alpha,
+        );
+        pixel.0 = Srgba::from(tinted).to_u8_array();
+    }
+}
+
+/// Describes where a glyph is copied from and to when rendering text to an
+/// image.
+struct GlyphPlacement {
+    /// The font texture page containing the glyph.
+    texture: AssetId<Image>,
+    /// The glyph's rectangle within its texture page.
+    source: URect,
+    /// The rectangle the glyph covers in the rendered text, relative to the
+    /// top-left corner of the first line. It lies outside of the text block
+    /// where glyph offsets move it there, e.g. for descenders. Its size
+    /// differs from that of `source` for glyphs of spans with a different
+    /// font or height than the text.
+    target: Rect,
+    /// The color the glyph is tinted with.
+    color: Color,
+}
+
+/// Determines where each glyph of the text goes in the rendered image.
+///
+/// Glyphs are positioned the same way the `atlas_sprites` path positions its
+/// sprites: at their place in the text layout, moved by the character's
+/// offsets from the font, and scaled relative to the text if their span has a
+/// different font or height.
+///
+/// # Parameters
+/// - `render_context`: The render context of the text, with scaling not
+///   applied.
+/// - `layouts`: The texture atlas layouts of the fonts.
+///
+/// # Returns
+/// One [`GlyphPlacement`] per glyph in the text layout.
+///
+/// # Errors
+/// Returns [`ImageFontRenderError::MissingTextureAsset`] if a glyph's rect
+/// can't be found in its texture atlas layout.
+fn glyph_placements(
+    render_context: &RenderContext<'_>,
+    layouts: &Assets<TextureAtlasLayout>,
+) -> Result<Vec<GlyphPlacement>, ImageFontRenderError> {
+    let line_height = render_context.line_height();
+
+    render_context
+        .layout()
+        .glyphs()
+        .map(|glyph| {
+            let character = glyph.span_character();
+            let source = render_context
+                .font_texture_atlas(character)
+                .texture_rect(layouts)
+                .ok_or(ImageFontRenderError::MissingTextureAsset)?;
+            let offsets = render_context.glyph_offsets(character);
+            let size =
+                source.size().as_vec2() * render_context.relative_scale(character.span_index);
+            let image_font = render_context.character_font(character);
+
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "neither the line count nor glyph sizes will ever be particularly large"
+            )]
+            let top_left = Vec2::new(
+                glyph.x_pos + offsets.x,
+                glyph.line_index as f32 * line_height - offsets.y,
+            );
+
+            Ok(GlyphPlacement {
+                texture: image_font.textures
+                    [image_font.atlas_character_map[&character.character].page_index]
+                    .id(),
+                source,
+                target: Rect::from_corners(top_left, top_left + size),
+                color: render_context.glyph_color(character.span_index),
+            })
+        })
+        .collect()
+}
+
+/// Converts a font texture page into an [`ImageBuffer`] that glyphs can be
+/// copied from.
+///
+/// # Parameters
+/// - `texture`: The font's texture page.
+///
+/// # Returns
+/// The image buffer of the texture page.
+///
+/// # Errors
+/// Returns [`ImageFontRenderError::UnknownError`] if the texture's data does
+/// not match its dimensions.
+fn font_texture_buffer(
+    texture: &Image,
+) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, ImageFontRenderError> {
+    let flat_data: Vec<u8> = texture
+        .data
+        .iter()
+        .flat_map(|pixel| pixel.iter().copied())
+        .collect();
+    ImageBuffer::from_raw(texture.width(), texture.height(), flat_data)
+        .ok_or(ImageFontRenderError::UnknownError)
+}
+
 /// Errors that can occur during the rendering of an `ImageFont`.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -356,6 +701,9 @@ pub enum ImageFontRenderError {
 /// This is used to abstract over different components (e.g., [`Sprite`] and
 /// [`ImageNode`]) that need to store a handle to an image rendered from text.
 trait ImageHandleHolder {
+    /// Returns the handle of the image this holder currently represents.
+    fn image_handle(&self) -> &Handle<Image>;
+
     /// Sets the handle for the image that this holder represents.
     ///
     /// This method is called after rendering text into an image
@@ -367,6 +715,10 @@ trait ImageHandleHolder {
 }
 
 impl ImageHandleHolder for Sprite {
+    fn image_handle(&self) -> &Handle<Image> {
+        &self.image
+    }
+
     fn set_image_handle(&mut self, image: Handle<Image>) {
         self.image = image;
     }
@@ -374,7 +726,14 @@ impl ImageHandleHolder for Sprite {
 
 #[cfg(feature = "ui")]
 impl ImageHandleHolder for ImageNode {
+    fn image_handle(&self) -> &Handle<Image> {
+        &self.image
+    }
+
     fn set_image_handle(&mut self, image: Handle<Image>) {
         self.image = image;
     }
 }
+
+#[cfg(test)]
+mod tests;
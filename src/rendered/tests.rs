@@ -0,0 +1,378 @@
+#![allow(clippy::unwrap_used, reason = "test code panics to indicate errors")]
+
+use super::*;
+use crate::tests::utils::{
+    initialize_app_with_loaded_example_font, ExampleFont, MONOSPACE_FONT_HEIGHT,
+    MONOSPACE_FONT_WIDTH,
+};
+use crate::ImageFontSpan;
+
+fn render(app: &App, image_font_text: &ImageFontText) -> Image {
+    render_styled(app, image_font_text, &ImageFontPreRenderedStyle::default())
+}
+
+fn render_styled(
+    app: &App,
+    image_font_text: &ImageFontText,
+    style: &ImageFontPreRenderedStyle,
+) -> Image {
+    let world = app.world();
+    render_text_to_image(
+        image_font_text,
+        style,
+        world.resource::<Assets<ImageFont>>(),
+        world.resource::<Assets<Image>>(),
+        world.resource::<Assets<TextureAtlasLayout>>(),
+    )
+    .unwrap()
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn image_covers_text_block() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let image_font_text = ImageFontText::default().text("AB\nC").font(handle);
+
+    let image = render(&app, &image_font_text);
+
+    assert_eq!(image.width(), 2 * MONOSPACE_FONT_WIDTH);
+    assert_eq!(image.height(), 2 * MONOSPACE_FONT_HEIGHT);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn image_covers_offset_glyphs() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    {
+        let mut image_fonts = app.world_mut().resource_mut::<Assets<ImageFont>>();
+        let atlas_character_map = &mut image_fonts.get_mut(&handle).unwrap().atlas_character_map;
+        // Sticks out to the left of the text block
+        atlas_character_map.get_mut(&'A').unwrap().offsets = Vec2::new(-2., 0.);
+        // Sticks out below the text block, like a descender
+        atlas_character_map.get_mut(&'B').unwrap().offsets = Vec2::new(0., -3.);
+    }
+    let image_font_text = ImageFontText::default().text("AB").font(handle.clone());
+
+    let image = render(&app, &image_font_text);
+    assert_eq!(image.width(), 2 * MONOSPACE_FONT_WIDTH + 2);
+    assert_eq!(image.height(), MONOSPACE_FONT_HEIGHT + 3);
+
+    // The whole image is scaled along with the text
+    // Twice the native height of the font
+    let image_font_text = image_font_text.font_height(24.);
+    let image = render(&app, &image_font_text);
+    assert_eq!(image.width(), 2 * (2 * MONOSPACE_FONT_WIDTH + 2));
+    assert_eq!(image.height(), 2 * (MONOSPACE_FONT_HEIGHT + 3));
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn renders_fallback_character_for_missing_characters() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    // Not part of the example font
+    let image_font_text = ImageFontText::default().text("AéB").font(handle.clone());
+
+    let image = render(&app, &image_font_text);
+    assert_eq!(image.width(), 2 * MONOSPACE_FONT_WIDTH);
+
+    app.world_mut()
+        .resource_mut::<Assets<ImageFont>>()
+        .get_mut(&handle)
+        .unwrap()
+        .fallback_character = Some('?');
+
+    let image = render(&app, &image_font_text);
+    assert_eq!(image.width(), 3 * MONOSPACE_FONT_WIDTH);
+    let expected = render(&app, &ImageFontText::default().text("A?B").font(handle));
+    assert_eq!(image.data, expected.data);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn takes_missing_characters_from_fallback_fonts() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let (primary, fallback) = {
+        let mut image_fonts = app.world_mut().resource_mut::<Assets<ImageFont>>();
+        let image_font = image_fonts.get(&handle).unwrap().clone();
+
+        let mut primary = image_font.clone();
+        primary.atlas_character_map.remove(&'B');
+        // The fallback font draws 'B' using the glyph of 'C'
+        let mut fallback = image_font.clone();
+        fallback.atlas_character_map = [('B', image_font.atlas_character_map[&'C'].clone())]
+            .into_iter()
+            .collect();
+
+        (image_fonts.add(primary), image_fonts.add(fallback))
+    };
+
+    let image_font_text = ImageFontText::default().text("AB").font(primary);
+    let image = render(&app, &image_font_text);
+    assert_eq!(image.width(), MONOSPACE_FONT_WIDTH);
+
+    let image = render(&app, &image_font_text.fallback_fonts(vec![fallback]));
+    let expected = render(&app, &ImageFontText::default().text("AC").font(handle));
+    assert_eq!(image.width(), 2 * MONOSPACE_FONT_WIDTH);
+    assert_eq!(image.data, expected.data);
+}
+
+#[test]
+#[cfg(feature = "bmf")]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn renders_packed_glyphs_in_white() {
+    use crate::tests::utils::{initialize_app_with_font, wait_until_loaded};
+
+    let (mut app, handle) = initialize_app_with_font("bmf_packed.txt.fnt");
+    wait_until_loaded(&mut app, &handle);
+    let image_font_text = ImageFontText::default().text("GA").font(handle);
+
+    let image = render(&app, &image_font_text);
+
+    // The channel a glyph is stored in becomes its alpha
+    assert_eq!(
+        image.data.unwrap(),
+        vec![255, 255, 255, 20, 255, 255, 255, 40]
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn letter_spacing_widens_image() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let image_font_text = ImageFontText::default().text("ABC").font(handle);
+
+    let style = ImageFontPreRenderedStyle::default().letter_spacing(LetterSpacing::Pixel(2));
+    let image = render_styled(&app, &image_font_text, &style);
+
+    // Like with atlas sprites, every character's width includes the spacing
+    assert_eq!(image.width(), 3 * (MONOSPACE_FONT_WIDTH + 2));
+    assert_eq!(image.height(), MONOSPACE_FONT_HEIGHT);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn scaling_mode_determines_image_size() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    // One and a half times the native height of the font
+    let image_font_text = ImageFontText::default()
+        .text("A")
+        .font(handle)
+        .font_height(18.);
+
+    for (scaling_mode, expected_width) in [
+        (FontScalingMode::Truncated, 7),
+        (FontScalingMode::Rounded, 8),
+        (FontScalingMode::Smooth, 8),
+    ] {
+        let style = ImageFontPreRenderedStyle::default().scaling_mode(scaling_mode);
+        let image = render_styled(&app, &image_font_text, &style);
+        assert_eq!(image.width(), expected_width, "{scaling_mode:?}");
+        assert_eq!(image.height(), 18, "{scaling_mode:?}");
+    }
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn color_tints_glyphs() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let image_font_text = ImageFontText::default().text("A").font(handle);
+
+    let untinted = render(&app, &image_font_text);
+    let style = ImageFontPreRenderedStyle::default().color(Color::srgb(1., 0., 0.));
+    let tinted = render_styled(&app, &image_font_text, &style);
+
+    let untinted = untinted.data.unwrap();
+    let tinted = tinted.data.unwrap();
+    assert!(tinted.iter().any(|&byte| byte > 0));
+    for (untinted, tinted) in untinted.chunks_exact(4).zip(tinted.chunks_exact(4)) {
+        assert_eq!(tinted, [untinted[0], 0, 0, untinted[3]]);
+    }
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn updates_rendered_image_in_place() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let entity = app
+        .world_mut()
+        .spawn((
+            ImageFontPreRenderedText::default(),
+            ImageFontText::default().text("A").font(handle),
+        ))
+        .id();
+    app.update();
+
+    let sprite_image = |app: &App| app.world().get::<Sprite>(entity).unwrap().image.clone();
+    let rendered_image = sprite_image(&app);
+    let image_count = app.world().resource::<Assets<Image>>().len();
+
+    app.world_mut()
+        .get_mut::<ImageFontText>(entity)
+        .unwrap()
+        .text = "B".into();
+    app.update();
+
+    assert_eq!(sprite_image(&app), rendered_image);
+    assert_eq!(app.world().resource::<Assets<Image>>().len(), image_count);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn renders_new_image_when_size_changes() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let entity = app
+        .world_mut()
+        .spawn((
+            ImageFontPreRenderedText::default(),
+            ImageFontText::default().text("A").font(handle),
+        ))
+        .id();
+    app.update();
+
+    let sprite_image = |app: &App| app.world().get::<Sprite>(entity).unwrap().image.clone();
+    let rendered_image = sprite_image(&app);
+
+    app.world_mut()
+        .get_mut::<ImageFontText>(entity)
+        .unwrap()
+        .text = "AB".into();
+    app.update();
+
+    let new_image = sprite_image(&app);
+    assert_ne!(new_image, rendered_image);
+    let image = app
+        .world()
+        .resource::<Assets<Image>>()
+        .get(&new_image)
+        .unwrap();
+    assert_eq!(image.width(), 2 * MONOSPACE_FONT_WIDTH);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn writes_unchanged_size_into_existing_texture() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    // Only present when there's a render app, which the test app lacks
+    app.init_resource::<RenderedImageWrites>();
+    let entity = app
+        .world_mut()
+        .spawn((
+            ImageFontPreRenderedText::default(),
+            ImageFontText::default().text("A").font(handle),
+        ))
+        .id();
+    app.update();
+
+    let sprite_image = |app: &App| app.world().get::<Sprite>(entity).unwrap().image.clone();
+    let rendered_image = sprite_image(&app);
+    let image_data = |app: &App| {
+        app.world()
+            .resource::<Assets<Image>>()
+            .get(&rendered_image)
+            .unwrap()
+            .data
+            .clone()
+    };
+    let original_data = image_data(&app);
+    assert!(app.world().resource::<RenderedImageWrites>().0.is_empty());
+
+    app.world_mut()
+        .get_mut::<ImageFontText>(entity)
+        .unwrap()
+        .text = "B".into();
+    app.update();
+
+    assert_eq!(sprite_image(&app), rendered_image);
+    let writes = &app.world().resource::<RenderedImageWrites>().0;
+    assert_eq!(writes.len(), 1);
+    assert_eq!(writes[0].0, rendered_image.id());
+    assert_ne!(Some(&writes[0].1), original_data.as_ref());
+    assert_eq!(image_data(&app), original_data);
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn does_not_overwrite_replaced_image() {
+    let (mut app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let entity = app
+        .world_mut()
+        .spawn((
+            ImageFontPreRenderedText::default(),
+            ImageFontText::default().text("A").font(handle),
+        ))
+        .id();
+    app.update();
+
+    // Someone else's image now occupies the sprite
+    let other_image = app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+    app.world_mut().get_mut::<Sprite>(entity).unwrap().image = other_image.clone();
+    app.world_mut()
+        .get_mut::<ImageFontText>(entity)
+        .unwrap()
+        .text = "AB".into();
+    app.update();
+
+    let sprite_image = app.world().get::<Sprite>(entity).unwrap().image.clone();
+    assert_ne!(sprite_image, other_image);
+    let images = app.world().resource::<Assets<Image>>();
+    assert_eq!(images.get(&other_image).unwrap().width(), 1);
+    assert_eq!(
+        images.get(&sprite_image).unwrap().width(),
+        2 * MONOSPACE_FONT_WIDTH
+    );
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn span_color_tints_its_glyphs() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let plain = render(
+        &app,
+        &ImageFontText::default().text("AA").font(handle.clone()),
+    );
+    let highlighted = render(
+        &app,
+        &ImageFontText::default()
+            .text("A")
+            .font(handle)
+            .spans(vec![ImageFontSpan::new("A").color(Color::srgb(1., 0., 0.))]),
+    );
+
+    assert_eq!(highlighted.width(), plain.width());
+    let plain = plain.data.unwrap();
+    let highlighted = highlighted.data.unwrap();
+    let row_length = 2 * MONOSPACE_FONT_WIDTH as usize;
+    for (plain_row, highlighted_row) in plain
+        .chunks_exact(4 * row_length)
+        .zip(highlighted.chunks_exact(4 * row_length))
+    {
+        let (plain_text, plain_span) = plain_row.split_at(plain_row.len() / 2);
+        let (highlighted_text, highlighted_span) = highlighted_row.split_at(plain_row.len() / 2);
+        assert_eq!(highlighted_text, plain_text);
+        for (plain, highlighted) in plain_span
+            .chunks_exact(4)
+            .zip(highlighted_span.chunks_exact(4))
+        {
+            assert_eq!(highlighted, [plain[0], 0, 0, plain[3]]);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
+fn span_font_height_resizes_its_glyphs() {
+    let (app, handle) = initialize_app_with_loaded_example_font(ExampleFont::Monospace);
+    let image_font_text = ImageFontText::default().text("A").font(handle).spans(vec![
+        // Twice the native height of the font
+        ImageFontSpan::new("A").font_height(24.),
+    ]);
+
+    let image = render(&app, &image_font_text);
+
+    // The larger glyph rests on the same line as the text, sticking out above
+    assert_eq!(image.width(), 3 * MONOSPACE_FONT_WIDTH);
+    assert_eq!(image.height(), 2 * MONOSPACE_FONT_HEIGHT);
+}
@@ -14,6 +14,9 @@
 //! - The `apply_scale` method centralizes scaling logic for consistent
 //!   behavior.
 //! - Default implementation (`Rounded`) balances precision and visual quality.
+//!
+//! It also defines the `FontHeightReference` enum, which determines which
+//! height a text's `font_height` is relative to.
 
 use bevy::prelude::*;
 
@@ -75,6 +78,28 @@ impl FontScalingMode {
     }
 }
 
+/// Determines which native height is scaled to a text's `font_height`, for
+/// fonts without [`line_metrics`](crate::ImageFont::line_metrics).
+///
+/// Fonts with line metrics are always scaled relative to their line height,
+/// regardless of this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum FontHeightReference {
+    /// Scales the tallest glyph in the text to the `font_height`.
+    ///
+    /// Texts made up of short glyphs, like `"..."`, are scaled up more than
+    /// texts with tall glyphs at the same `font_height`. This is the default.
+    #[default]
+    TallestGlyphInText,
+
+    /// Scales the tallest glyph in the text's font to the `font_height`,
+    /// whether or not the text contains it.
+    ///
+    /// All texts with the same font and `font_height` are scaled by the same
+    /// factor, so their glyphs have identical sizes.
+    TallestGlyphInFont,
+}
+
 #[cfg(test)]
 mod tests {
     use float_eq::assert_float_eq;
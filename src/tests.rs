@@ -36,6 +36,36 @@ fn mapped_atlas_layout_from_char_map_creates_correct_character_map_and_layout()
     );
 }
 
+#[test]
+fn insert_atlas_glyph_adds_page_once_and_aligns_baseline() {
+    let mut image_font = ImageFont {
+        line_metrics: Some(ImageFontLineMetrics::new(12, 9)),
+        ..default()
+    };
+    let texture = Handle::default();
+    let atlas = |index| TextureAtlas {
+        layout: Handle::default(),
+        index,
+    };
+
+    image_font.insert_atlas_glyph('\u{e000}', texture.clone(), atlas(0), None);
+    let icon = image_font
+        .insert_atlas_glyph('\u{e001}', texture, atlas(3), Some(11))
+        .clone();
+
+    assert_eq!(image_font.textures.len(), 1);
+    assert_eq!(image_font.atlas_layouts.len(), 1);
+    assert_eq!(icon.page_index, 0);
+    assert_eq!(icon.character_index, 3);
+    // The icon's baseline is 2 pixels further from its top than the font's is
+    // from the top of the line, so the icon is moved up by 2 pixels
+    assert_eq!(icon.offsets, Vec2::new(0., 2.));
+    assert_eq!(
+        image_font.atlas_character_map[&'\u{e000}'].offsets,
+        Vec2::ZERO
+    );
+}
+
 #[test]
 #[cfg_attr(feature = "gizmos", ignore = "test cannot run with `gizmos` feature")]
 fn image_font_plugin_initialization() {
@@ -78,6 +78,30 @@ fn changed_after_loaded_with_dependencies_event() {
     });
 }
 
+#[test]
+fn changed_after_fallback_font_loaded() {
+    let (mut app, mut system_state, _) = setup_app_system_state_and_entity();
+
+    clear_query_state(&mut app, &mut system_state);
+
+    app.update();
+
+    app.world_mut()
+        .send_event(AssetEvent::LoadedWithDependencies {
+            id: fallback_font_id(),
+        });
+
+    app.update();
+
+    // Verify the change state of the component: `is_changed` should be true after
+    // `app.update()` with `AssetEvent::LoadedWithDependencies` event on one of the
+    // text's fallback fonts.
+    with_image_font_text(&mut app, &mut system_state, |image_font_text| {
+        assert!(!image_font_text.is_added());
+        assert!(image_font_text.is_changed());
+    });
+}
+
 #[test]
 fn not_changed_after_events_on_other_fonts() {
     let (mut app, mut system_state, _) = setup_app_system_state_and_entity();
@@ -150,7 +174,13 @@ fn setup_app_system_state_and_entity() -> (
     app.world_mut().spawn(ImageFontText {
         text: String::from("Hello"),
         font: font_handle.clone(),
+        fallback_fonts: vec![Handle::Weak(fallback_font_id())],
         font_height: Some(36.0),
+        font_height_reference: default(),
+        line_height: None,
+        max_width: None,
+        line_alignment: default(),
+        spans: Vec::new(),
     });
 
     let system_state: SystemState<Query<Ref<ImageFontText>>> = SystemState::new(app.world_mut());
@@ -158,6 +188,15 @@ fn setup_app_system_state_and_entity() -> (
     (app, system_state, font_handle)
 }
 
+/// The id of the fallback font of the `ImageFontText` entity spawned by
+/// [`setup_app_system_state_and_entity`].
+fn fallback_font_id() -> AssetId<ImageFont> {
+    AssetId::Index {
+        index: AssetIndex::from_bits(7),
+        marker: PhantomData,
+    }
+}
+
 /// Helper function to run code on the `Ref<ImageFontText>`.
 fn with_image_font_text(
     app: &mut App,
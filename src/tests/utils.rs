@@ -46,7 +46,9 @@ pub(crate) fn wait_until_loaded<T: Asset>(app: &mut App, handle: &Handle<T>) {
     }
 }
 
-fn initialize_app_with_font(font_path: impl AsRef<Utf8Path>) -> (App, Handle<ImageFont>) {
+pub(crate) fn initialize_app_with_font(
+    font_path: impl AsRef<Utf8Path>,
+) -> (App, Handle<ImageFont>) {
     let mut app = App::new();
 
     app.add_plugins((MinimalPlugins, AssetPlugin::default()));
@@ -21,15 +21,15 @@ use bevy::winit::WinitPlugin;
 use bevy::{
     app::{AppExit, ScheduleRunnerPlugin},
     core_pipeline::tonemapping::Tonemapping,
-    ecs::system::IntoSystemConfig,
+    ecs::{schedule::IntoScheduleConfigs, system::ScheduleSystem},
     prelude::*,
     render::{
         camera::RenderTarget,
         render_asset::{RenderAssetUsages, RenderAssets},
         render_graph::{self, NodeRunError, RenderGraph, RenderGraphContext, RenderLabel},
         render_resource::{
-            Buffer, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Extent3d,
-            ImageCopyBuffer, ImageDataLayout, Maintain, MapMode, TextureDimension, TextureFormat,
+            Buffer, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Extent3d, Maintain,
+            MapMode, TexelCopyBufferInfo, TexelCopyBufferLayout, TextureDimension, TextureFormat,
             TextureUsages,
         },
         renderer::{RenderContext, RenderDevice, RenderQueue},
@@ -72,7 +72,7 @@ struct RenderWorldSender(Sender<Vec<u8>>);
 pub(crate) fn prepare_app<M>(
     category: impl Into<String>,
     image_name: impl Into<String>,
-    setup_system: impl IntoSystemConfig<M>,
+    setup_system: impl IntoScheduleConfigs<ScheduleSystem, M>,
 ) {
     let mut app = App::new();
 
@@ -255,7 +255,7 @@ fn setup_render_target(
     commands.spawn(ImageToSave(cpu_image_handle));
 
     scene_controller.name = scene_name;
-    RenderTarget::Image(render_target_image_handle)
+    RenderTarget::Image(render_target_image_handle.into())
 }
 
 fn create_render_target_image(images: &mut ResMut<Assets<Image>>, size: Extent3d) -> Handle<Image> {
@@ -379,20 +379,20 @@ impl render_graph::Node for ImageCopyDriver {
             // be little bit wider This should be taken into account at copy
             // from buffer stage
             let padded_bytes_per_row = RenderDevice::align_copy_bytes_per_row(
-                (src_image.size.x as usize / block_dimensions.0 as usize) * block_size as usize,
+                (src_image.size.width as usize / block_dimensions.0 as usize) * block_size as usize,
             );
 
             let texture_extent = Extent3d {
-                width: src_image.size.x,
-                height: src_image.size.y,
+                width: src_image.size.width,
+                height: src_image.size.height,
                 depth_or_array_layers: 1,
             };
 
             encoder.copy_texture_to_buffer(
                 src_image.texture.as_image_copy(),
-                ImageCopyBuffer {
+                TexelCopyBufferInfo {
                     buffer: &image_copier.buffer,
-                    layout: ImageDataLayout {
+                    layout: TexelCopyBufferLayout {
                         offset: 0,
                         #[expect(
                             clippy::cast_possible_truncation,
@@ -555,7 +555,7 @@ fn render_scene(
         return;
     }
 
-    let image = images_to_save.single();
+    let image = images_to_save.single().unwrap();
     let img_bytes = images.get_mut(image.id()).unwrap();
     let img = prepare_image_buffer(image_data, img_bytes);
 
@@ -632,7 +632,7 @@ fn compare_images(paths: ImagePaths, mut app_exit_writer: EventWriter<'_, AppExi
 
     // Once we're done producing and comparing our images and we got this far, it's
     // time to exit with success to indicate nothing needs doing.
-    app_exit_writer.send(AppExit::Success);
+    app_exit_writer.write(AppExit::Success);
 }
 
 fn fetch_latest_image_data(receiver: &MainWorldReceiver) -> Vec<u8> {
@@ -656,15 +656,17 @@ fn prepare_image_buffer(
     // If row_bytes == aligned_row_bytes, we can copy directly. Otherwise, we must
     // adjust alignment.
     if row_bytes == aligned_row_bytes {
-        img_bytes.data.clone_from(&image_data);
+        img_bytes.data = Some(image_data);
     } else {
         // Extract only the meaningful part of each row, ignoring padding
-        img_bytes.data = image_data
-            .chunks(aligned_row_bytes)
-            .take(img_bytes.height() as usize)
-            .flat_map(|row| &row[..row_bytes.min(row.len())])
-            .copied()
-            .collect();
+        img_bytes.data = Some(
+            image_data
+                .chunks(aligned_row_bytes)
+                .take(img_bytes.height() as usize)
+                .flat_map(|row| &row[..row_bytes.min(row.len())])
+                .copied()
+                .collect(),
+        );
     }
 
     // Create RGBA Image Buffer